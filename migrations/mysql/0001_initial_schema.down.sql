@@ -0,0 +1,8 @@
+DROP TABLE IF EXISTS sec_submission_file_hash;
+DROP TABLE IF EXISTS filing_entity_common_stock_shares_outstanding;
+DROP TABLE IF EXISTS filing_common_stock_shares_outstanding;
+DROP TABLE IF EXISTS filing_assets;
+DROP TABLE IF EXISTS security_filing;
+DROP TABLE IF EXISTS security_exchange_ticker;
+DROP TABLE IF EXISTS security;
+DROP TABLE IF EXISTS asset;
@@ -0,0 +1,96 @@
+-- Schema the project was originally deployed with. IF NOT EXISTS lets a database that was set up by hand adopt the
+-- migration history without losing data.
+
+CREATE TABLE IF NOT EXISTS asset (
+	id INT NOT NULL AUTO_INCREMENT,
+	industry VARCHAR(255) NOT NULL,
+	sector VARCHAR(255) NOT NULL,
+	name VARCHAR(255) NOT NULL,
+	`type` VARCHAR(64) NOT NULL,
+	PRIMARY KEY (id)
+);
+
+CREATE TABLE IF NOT EXISTS security (
+	cik VARCHAR(10) NOT NULL,
+	asset_id INT NOT NULL,
+	business_street1 VARCHAR(255) NOT NULL DEFAULT '',
+	business_city VARCHAR(255) NOT NULL DEFAULT '',
+	business_country VARCHAR(255) NOT NULL DEFAULT '',
+	business_state VARCHAR(255) NOT NULL DEFAULT '',
+	business_zip VARCHAR(32) NOT NULL DEFAULT '',
+	description TEXT NOT NULL,
+	ein VARCHAR(32) NOT NULL DEFAULT '',
+	entity_type VARCHAR(64) NOT NULL DEFAULT '',
+	phone VARCHAR(64) NOT NULL DEFAULT '',
+	sic VARCHAR(8) NOT NULL DEFAULT '',
+	website VARCHAR(255) NOT NULL DEFAULT '',
+	PRIMARY KEY (cik),
+	UNIQUE KEY uq_security_asset_id (asset_id),
+	CONSTRAINT fk_security_asset FOREIGN KEY (asset_id) REFERENCES asset (id)
+);
+
+CREATE TABLE IF NOT EXISTS security_exchange_ticker (
+	id BIGINT NOT NULL AUTO_INCREMENT,
+	security_cik VARCHAR(10) NOT NULL,
+	exchange VARCHAR(64) NOT NULL,
+	ticker VARCHAR(32) NOT NULL,
+	PRIMARY KEY (id),
+	KEY idx_security_exchange_ticker_security_cik (security_cik),
+	KEY idx_security_exchange_ticker_ticker (ticker),
+	CONSTRAINT fk_security_exchange_ticker_security FOREIGN KEY (security_cik) REFERENCES security (cik)
+);
+
+CREATE TABLE IF NOT EXISTS security_filing (
+	id BIGINT NOT NULL AUTO_INCREMENT,
+	security_cik VARCHAR(10) NOT NULL,
+	accession_number VARCHAR(20) NOT NULL,
+	form VARCHAR(32) NOT NULL,
+	filing_date DATE NOT NULL,
+	report_date DATE NULL,
+	acceptance DATETIME NOT NULL,
+	PRIMARY KEY (id),
+	UNIQUE KEY uq_security_filing_accession_number (accession_number),
+	KEY idx_security_filing_security_cik (security_cik),
+	CONSTRAINT fk_security_filing_security FOREIGN KEY (security_cik) REFERENCES security (cik)
+);
+
+CREATE TABLE IF NOT EXISTS filing_assets (
+	id BIGINT NOT NULL AUTO_INCREMENT,
+	security_filing_accession_number VARCHAR(20) NOT NULL,
+	end DATE NOT NULL,
+	fp VARCHAR(8) NOT NULL,
+	fy INT NOT NULL,
+	val BIGINT NOT NULL,
+	PRIMARY KEY (id),
+	KEY idx_filing_assets_accession_number_end (security_filing_accession_number, end)
+);
+
+CREATE TABLE IF NOT EXISTS filing_common_stock_shares_outstanding (
+	id BIGINT NOT NULL AUTO_INCREMENT,
+	security_filing_accession_number VARCHAR(20) NOT NULL,
+	end DATE NOT NULL,
+	fp VARCHAR(8) NOT NULL,
+	fy INT NOT NULL,
+	val BIGINT NOT NULL,
+	PRIMARY KEY (id),
+	KEY idx_filing_csso_accession_number (security_filing_accession_number)
+);
+
+CREATE TABLE IF NOT EXISTS filing_entity_common_stock_shares_outstanding (
+	id BIGINT NOT NULL AUTO_INCREMENT,
+	security_filing_accession_number VARCHAR(20) NOT NULL,
+	end DATE NOT NULL,
+	fp VARCHAR(8) NOT NULL,
+	fy INT NOT NULL,
+	val BIGINT NOT NULL,
+	PRIMARY KEY (id),
+	KEY idx_filing_ecsso_accession_number (security_filing_accession_number)
+);
+
+CREATE TABLE IF NOT EXISTS sec_submission_file_hash (
+	id BIGINT NOT NULL AUTO_INCREMENT,
+	submission_file_name VARCHAR(64) NOT NULL,
+	hash VARCHAR(64) NOT NULL,
+	PRIMARY KEY (id),
+	UNIQUE KEY uq_sec_submission_file_hash_file_name (submission_file_name)
+);
@@ -0,0 +1,9 @@
+TRUNCATE TABLE sec_submission_file_hash;
+
+ALTER TABLE sec_submission_file_hash
+	DROP INDEX uq_sec_submission_file_hash_archive_file_name,
+	DROP COLUMN archive,
+	DROP COLUMN crc32,
+	DROP COLUMN uncompressed_size,
+	MODIFY COLUMN hash VARCHAR(64) NOT NULL,
+	ADD UNIQUE KEY uq_sec_submission_file_hash_file_name (submission_file_name);
@@ -0,0 +1,11 @@
+-- Fingerprints now come from the zip central directory and are kept per archive. The old hashes cannot be compared
+-- with the new fingerprints, so they are dropped and every file is synchronized once more.
+TRUNCATE TABLE sec_submission_file_hash;
+
+ALTER TABLE sec_submission_file_hash
+	DROP INDEX uq_sec_submission_file_hash_file_name,
+	ADD COLUMN archive VARCHAR(16) NOT NULL AFTER id,
+	ADD COLUMN crc32 INT UNSIGNED NOT NULL AFTER submission_file_name,
+	ADD COLUMN uncompressed_size BIGINT UNSIGNED NOT NULL AFTER crc32,
+	MODIFY COLUMN hash VARCHAR(64) NULL,
+	ADD UNIQUE KEY uq_sec_submission_file_hash_archive_file_name (archive, submission_file_name);
@@ -0,0 +1,32 @@
+ALTER TABLE filing_assets
+	DROP KEY idx_filing_assets_filed,
+	DROP KEY idx_filing_assets_form,
+	DROP KEY idx_filing_assets_frame,
+	DROP COLUMN unit,
+	DROP COLUMN start,
+	MODIFY COLUMN val BIGINT NOT NULL,
+	DROP COLUMN filed,
+	DROP COLUMN form,
+	DROP COLUMN frame;
+
+ALTER TABLE filing_common_stock_shares_outstanding
+	DROP KEY idx_filing_common_stock_shares_outstanding_filed,
+	DROP KEY idx_filing_common_stock_shares_outstanding_form,
+	DROP KEY idx_filing_common_stock_shares_outstanding_frame,
+	DROP COLUMN unit,
+	DROP COLUMN start,
+	MODIFY COLUMN val BIGINT NOT NULL,
+	DROP COLUMN filed,
+	DROP COLUMN form,
+	DROP COLUMN frame;
+
+ALTER TABLE filing_entity_common_stock_shares_outstanding
+	DROP KEY idx_filing_entity_common_stock_shares_outstanding_filed,
+	DROP KEY idx_filing_entity_common_stock_shares_outstanding_form,
+	DROP KEY idx_filing_entity_common_stock_shares_outstanding_frame,
+	DROP COLUMN unit,
+	DROP COLUMN start,
+	MODIFY COLUMN val BIGINT NOT NULL,
+	DROP COLUMN filed,
+	DROP COLUMN form,
+	DROP COLUMN frame;
@@ -0,0 +1,34 @@
+-- Start, filed, form, frame and unit of every XBRL fact, and exact decimal values
+
+ALTER TABLE filing_assets
+	ADD COLUMN unit VARCHAR(32) NOT NULL DEFAULT '' AFTER security_filing_accession_number,
+	ADD COLUMN start DATE NULL AFTER unit,
+	MODIFY COLUMN val DECIMAL(38, 10) NOT NULL,
+	ADD COLUMN filed DATE NULL AFTER val,
+	ADD COLUMN form VARCHAR(32) NOT NULL DEFAULT '' AFTER filed,
+	ADD COLUMN frame VARCHAR(16) NULL AFTER form,
+	ADD KEY idx_filing_assets_filed (filed),
+	ADD KEY idx_filing_assets_form (form),
+	ADD KEY idx_filing_assets_frame (frame);
+
+ALTER TABLE filing_common_stock_shares_outstanding
+	ADD COLUMN unit VARCHAR(32) NOT NULL DEFAULT '' AFTER security_filing_accession_number,
+	ADD COLUMN start DATE NULL AFTER unit,
+	MODIFY COLUMN val DECIMAL(38, 10) NOT NULL,
+	ADD COLUMN filed DATE NULL AFTER val,
+	ADD COLUMN form VARCHAR(32) NOT NULL DEFAULT '' AFTER filed,
+	ADD COLUMN frame VARCHAR(16) NULL AFTER form,
+	ADD KEY idx_filing_common_stock_shares_outstanding_filed (filed),
+	ADD KEY idx_filing_common_stock_shares_outstanding_form (form),
+	ADD KEY idx_filing_common_stock_shares_outstanding_frame (frame);
+
+ALTER TABLE filing_entity_common_stock_shares_outstanding
+	ADD COLUMN unit VARCHAR(32) NOT NULL DEFAULT '' AFTER security_filing_accession_number,
+	ADD COLUMN start DATE NULL AFTER unit,
+	MODIFY COLUMN val DECIMAL(38, 10) NOT NULL,
+	ADD COLUMN filed DATE NULL AFTER val,
+	ADD COLUMN form VARCHAR(32) NOT NULL DEFAULT '' AFTER filed,
+	ADD COLUMN frame VARCHAR(16) NULL AFTER form,
+	ADD KEY idx_filing_entity_common_stock_shares_outstanding_filed (filed),
+	ADD KEY idx_filing_entity_common_stock_shares_outstanding_form (form),
+	ADD KEY idx_filing_entity_common_stock_shares_outstanding_frame (frame);
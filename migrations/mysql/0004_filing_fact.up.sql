@@ -0,0 +1,24 @@
+-- Every configured XBRL concept, from any taxonomy and in any unit
+CREATE TABLE filing_fact (
+	id BIGINT NOT NULL AUTO_INCREMENT,
+	security_filing_accession_number VARCHAR(20) NOT NULL,
+	canonical_concept VARCHAR(255) NOT NULL,
+	taxonomy VARCHAR(64) NOT NULL,
+	concept VARCHAR(255) NOT NULL,
+	unit VARCHAR(32) NOT NULL,
+	start DATE NULL,
+	end DATE NOT NULL,
+	fp VARCHAR(8) NOT NULL,
+	fy INT NOT NULL,
+	val DECIMAL(38, 10) NOT NULL,
+	filed DATE NOT NULL,
+	form VARCHAR(32) NOT NULL,
+	frame VARCHAR(16) NULL,
+	PRIMARY KEY (id),
+	KEY idx_filing_fact_identity (security_filing_accession_number, taxonomy, concept, unit, start, end),
+	KEY idx_filing_fact_taxonomy_concept (taxonomy, concept),
+	KEY idx_filing_fact_canonical_concept (canonical_concept),
+	KEY idx_filing_fact_filed (filed),
+	KEY idx_filing_fact_form (form),
+	KEY idx_filing_fact_frame (frame)
+);
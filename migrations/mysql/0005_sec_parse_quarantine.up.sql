@@ -0,0 +1,15 @@
+-- Archive files that failed to parse, retried on every run until they parse again
+CREATE TABLE sec_parse_quarantine (
+	id BIGINT NOT NULL AUTO_INCREMENT,
+	archive VARCHAR(16) NOT NULL,
+	file_name VARCHAR(64) NOT NULL,
+	field VARCHAR(255) NOT NULL,
+	item_index BIGINT NULL,
+	raw_value TEXT NULL,
+	message TEXT NOT NULL,
+	attempts INT NOT NULL DEFAULT 1,
+	first_seen_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+	last_seen_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+	PRIMARY KEY (id),
+	UNIQUE KEY uq_sec_parse_quarantine_archive_file_name (archive, file_name)
+);
@@ -0,0 +1,15 @@
+ALTER TABLE security
+	DROP COLUMN mailing_street1,
+	DROP COLUMN mailing_city,
+	DROP COLUMN mailing_country,
+	DROP COLUMN mailing_state,
+	DROP COLUMN mailing_zip,
+	DROP COLUMN category,
+	DROP COLUMN fiscal_year_end,
+	DROP COLUMN flags,
+	DROP COLUMN insider_transaction_for_issuer_exists,
+	DROP COLUMN insider_transaction_for_owner_exists,
+	DROP COLUMN investor_website,
+	DROP COLUMN owner_org,
+	DROP COLUMN sic_description,
+	DROP COLUMN state_of_incorporation;
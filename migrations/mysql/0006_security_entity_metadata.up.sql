@@ -0,0 +1,16 @@
+-- Mailing address and the rest of the entity metadata of the submissions JSON
+ALTER TABLE security
+	ADD COLUMN mailing_street1 VARCHAR(255) NOT NULL DEFAULT '' AFTER business_zip,
+	ADD COLUMN mailing_city VARCHAR(255) NOT NULL DEFAULT '' AFTER mailing_street1,
+	ADD COLUMN mailing_country VARCHAR(255) NOT NULL DEFAULT '' AFTER mailing_city,
+	ADD COLUMN mailing_state VARCHAR(255) NOT NULL DEFAULT '' AFTER mailing_country,
+	ADD COLUMN mailing_zip VARCHAR(32) NOT NULL DEFAULT '' AFTER mailing_state,
+	ADD COLUMN category VARCHAR(255) NOT NULL DEFAULT '' AFTER mailing_zip,
+	ADD COLUMN fiscal_year_end VARCHAR(4) NOT NULL DEFAULT '' AFTER entity_type,
+	ADD COLUMN flags VARCHAR(255) NOT NULL DEFAULT '' AFTER fiscal_year_end,
+	ADD COLUMN insider_transaction_for_issuer_exists BOOLEAN NOT NULL DEFAULT FALSE AFTER flags,
+	ADD COLUMN insider_transaction_for_owner_exists BOOLEAN NOT NULL DEFAULT FALSE AFTER insider_transaction_for_issuer_exists,
+	ADD COLUMN investor_website VARCHAR(255) NOT NULL DEFAULT '' AFTER insider_transaction_for_owner_exists,
+	ADD COLUMN owner_org VARCHAR(255) NOT NULL DEFAULT '' AFTER investor_website,
+	ADD COLUMN sic_description VARCHAR(255) NOT NULL DEFAULT '' AFTER sic,
+	ADD COLUMN state_of_incorporation VARCHAR(8) NOT NULL DEFAULT '' AFTER sic_description;
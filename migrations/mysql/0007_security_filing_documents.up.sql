@@ -0,0 +1,12 @@
+-- The rest of the filing record of filings.recent. primary_document stays NULL on filings stored before this
+-- migration until the next sync fills it in.
+ALTER TABLE security_filing
+	ADD COLUMN act VARCHAR(8) NOT NULL DEFAULT '' AFTER acceptance,
+	ADD COLUMN file_number VARCHAR(64) NOT NULL DEFAULT '' AFTER act,
+	ADD COLUMN film_number VARCHAR(64) NOT NULL DEFAULT '' AFTER file_number,
+	ADD COLUMN items VARCHAR(255) NOT NULL DEFAULT '' AFTER film_number,
+	ADD COLUMN size BIGINT NULL AFTER items,
+	ADD COLUMN is_xbrl BOOLEAN NOT NULL DEFAULT FALSE AFTER size,
+	ADD COLUMN is_inline_xbrl BOOLEAN NOT NULL DEFAULT FALSE AFTER is_xbrl,
+	ADD COLUMN primary_document VARCHAR(255) NULL AFTER is_inline_xbrl,
+	ADD COLUMN primary_doc_description VARCHAR(255) NOT NULL DEFAULT '' AFTER primary_document;
@@ -0,0 +1,11 @@
+-- Former names of a security, from formerNames of the submissions JSON
+CREATE TABLE security_name_history (
+	id BIGINT NOT NULL AUTO_INCREMENT,
+	security_cik VARCHAR(10) NOT NULL,
+	name VARCHAR(255) NOT NULL,
+	from_date DATE NULL,
+	to_date DATE NULL,
+	PRIMARY KEY (id),
+	KEY idx_security_name_history_security_cik (security_cik),
+	CONSTRAINT fk_security_name_history_security FOREIGN KEY (security_cik) REFERENCES security (cik)
+);
@@ -0,0 +1,9 @@
+DELETE FROM security_exchange_ticker WHERE valid_to IS NOT NULL;
+
+UPDATE security_exchange_ticker SET exchange = 'null' WHERE exchange IS NULL;
+
+ALTER TABLE security_exchange_ticker
+	DROP KEY idx_security_exchange_ticker_ticker_period,
+	DROP COLUMN valid_from,
+	DROP COLUMN valid_to,
+	MODIFY COLUMN exchange VARCHAR(64) NOT NULL;
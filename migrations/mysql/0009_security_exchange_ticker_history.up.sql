@@ -0,0 +1,10 @@
+-- Listing periods instead of delete-and-insert. valid_to is exclusive and NULL while the pair is listed. Rows from
+-- before this migration keep a NULL valid_from, meaning listed since an unknown date.
+ALTER TABLE security_exchange_ticker
+	MODIFY COLUMN exchange VARCHAR(64) NULL,
+	ADD COLUMN valid_from DATE NULL AFTER ticker,
+	ADD COLUMN valid_to DATE NULL AFTER valid_from,
+	ADD KEY idx_security_exchange_ticker_ticker_period (ticker, valid_from, valid_to);
+
+-- Unknown exchanges used to be stored as the string "null"
+UPDATE security_exchange_ticker SET exchange = NULL WHERE exchange = 'null';
@@ -0,0 +1,92 @@
+-- Schema the project was originally deployed with, kept as the first step so both backends share version numbers.
+-- "end" is a reserved word in PostgreSQL and has to be quoted.
+
+CREATE TABLE IF NOT EXISTS asset (
+	id SERIAL PRIMARY KEY,
+	industry VARCHAR(255) NOT NULL,
+	sector VARCHAR(255) NOT NULL,
+	name VARCHAR(255) NOT NULL,
+	type VARCHAR(64) NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS security (
+	cik VARCHAR(10) NOT NULL PRIMARY KEY,
+	asset_id INTEGER NOT NULL REFERENCES asset (id),
+	business_street1 VARCHAR(255) NOT NULL DEFAULT '',
+	business_city VARCHAR(255) NOT NULL DEFAULT '',
+	business_country VARCHAR(255) NOT NULL DEFAULT '',
+	business_state VARCHAR(255) NOT NULL DEFAULT '',
+	business_zip VARCHAR(32) NOT NULL DEFAULT '',
+	description TEXT NOT NULL,
+	ein VARCHAR(32) NOT NULL DEFAULT '',
+	entity_type VARCHAR(64) NOT NULL DEFAULT '',
+	phone VARCHAR(64) NOT NULL DEFAULT '',
+	sic VARCHAR(8) NOT NULL DEFAULT '',
+	website VARCHAR(255) NOT NULL DEFAULT '',
+	CONSTRAINT uq_security_asset_id UNIQUE (asset_id)
+);
+
+CREATE TABLE IF NOT EXISTS security_exchange_ticker (
+	id BIGSERIAL PRIMARY KEY,
+	security_cik VARCHAR(10) NOT NULL REFERENCES security (cik),
+	exchange VARCHAR(64) NOT NULL,
+	ticker VARCHAR(32) NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_security_exchange_ticker_security_cik ON security_exchange_ticker (security_cik);
+CREATE INDEX IF NOT EXISTS idx_security_exchange_ticker_ticker ON security_exchange_ticker (ticker);
+
+CREATE TABLE IF NOT EXISTS security_filing (
+	id BIGSERIAL PRIMARY KEY,
+	security_cik VARCHAR(10) NOT NULL REFERENCES security (cik),
+	accession_number VARCHAR(20) NOT NULL,
+	form VARCHAR(32) NOT NULL,
+	filing_date DATE NOT NULL,
+	report_date DATE NULL,
+	acceptance TIMESTAMP NOT NULL,
+	CONSTRAINT uq_security_filing_accession_number UNIQUE (accession_number)
+);
+
+CREATE INDEX IF NOT EXISTS idx_security_filing_security_cik ON security_filing (security_cik);
+
+CREATE TABLE IF NOT EXISTS filing_assets (
+	id BIGSERIAL PRIMARY KEY,
+	security_filing_accession_number VARCHAR(20) NOT NULL,
+	"end" DATE NOT NULL,
+	fp VARCHAR(8) NOT NULL,
+	fy INTEGER NOT NULL,
+	val BIGINT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_filing_assets_accession_number_end ON filing_assets (security_filing_accession_number, "end");
+
+CREATE TABLE IF NOT EXISTS filing_common_stock_shares_outstanding (
+	id BIGSERIAL PRIMARY KEY,
+	security_filing_accession_number VARCHAR(20) NOT NULL,
+	"end" DATE NOT NULL,
+	fp VARCHAR(8) NOT NULL,
+	fy INTEGER NOT NULL,
+	val BIGINT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_filing_csso_accession_number
+	ON filing_common_stock_shares_outstanding (security_filing_accession_number);
+
+CREATE TABLE IF NOT EXISTS filing_entity_common_stock_shares_outstanding (
+	id BIGSERIAL PRIMARY KEY,
+	security_filing_accession_number VARCHAR(20) NOT NULL,
+	"end" DATE NOT NULL,
+	fp VARCHAR(8) NOT NULL,
+	fy INTEGER NOT NULL,
+	val BIGINT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_filing_ecsso_accession_number
+	ON filing_entity_common_stock_shares_outstanding (security_filing_accession_number);
+
+CREATE TABLE IF NOT EXISTS sec_submission_file_hash (
+	id BIGSERIAL PRIMARY KEY,
+	submission_file_name VARCHAR(64) NOT NULL,
+	hash VARCHAR(64) NOT NULL,
+	CONSTRAINT uq_sec_submission_file_hash_file_name UNIQUE (submission_file_name)
+);
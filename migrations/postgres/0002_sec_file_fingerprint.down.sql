@@ -0,0 +1,9 @@
+TRUNCATE TABLE sec_submission_file_hash;
+
+ALTER TABLE sec_submission_file_hash
+	DROP CONSTRAINT uq_sec_submission_file_hash_archive_file_name,
+	DROP COLUMN archive,
+	DROP COLUMN crc32,
+	DROP COLUMN uncompressed_size,
+	ALTER COLUMN hash SET NOT NULL,
+	ADD CONSTRAINT uq_sec_submission_file_hash_file_name UNIQUE (submission_file_name);
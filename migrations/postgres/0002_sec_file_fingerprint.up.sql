@@ -0,0 +1,12 @@
+-- Fingerprints now come from the zip central directory and are kept per archive. The old hashes cannot be compared
+-- with the new fingerprints, so they are dropped and every file is synchronized once more. PostgreSQL has no
+-- unsigned integers, crc32 is stored in a BIGINT.
+TRUNCATE TABLE sec_submission_file_hash;
+
+ALTER TABLE sec_submission_file_hash
+	DROP CONSTRAINT uq_sec_submission_file_hash_file_name,
+	ADD COLUMN archive VARCHAR(16) NOT NULL,
+	ADD COLUMN crc32 BIGINT NOT NULL,
+	ADD COLUMN uncompressed_size BIGINT NOT NULL,
+	ALTER COLUMN hash DROP NOT NULL,
+	ADD CONSTRAINT uq_sec_submission_file_hash_archive_file_name UNIQUE (archive, submission_file_name);
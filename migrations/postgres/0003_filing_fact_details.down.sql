@@ -0,0 +1,35 @@
+DROP INDEX idx_filing_assets_filed;
+DROP INDEX idx_filing_assets_form;
+DROP INDEX idx_filing_assets_frame;
+
+ALTER TABLE filing_assets
+	DROP COLUMN unit,
+	DROP COLUMN start,
+	ALTER COLUMN val TYPE BIGINT,
+	DROP COLUMN filed,
+	DROP COLUMN form,
+	DROP COLUMN frame;
+
+DROP INDEX idx_filing_common_stock_shares_outstanding_filed;
+DROP INDEX idx_filing_common_stock_shares_outstanding_form;
+DROP INDEX idx_filing_common_stock_shares_outstanding_frame;
+
+ALTER TABLE filing_common_stock_shares_outstanding
+	DROP COLUMN unit,
+	DROP COLUMN start,
+	ALTER COLUMN val TYPE BIGINT,
+	DROP COLUMN filed,
+	DROP COLUMN form,
+	DROP COLUMN frame;
+
+DROP INDEX idx_filing_entity_common_stock_shares_outstanding_filed;
+DROP INDEX idx_filing_entity_common_stock_shares_outstanding_form;
+DROP INDEX idx_filing_entity_common_stock_shares_outstanding_frame;
+
+ALTER TABLE filing_entity_common_stock_shares_outstanding
+	DROP COLUMN unit,
+	DROP COLUMN start,
+	ALTER COLUMN val TYPE BIGINT,
+	DROP COLUMN filed,
+	DROP COLUMN form,
+	DROP COLUMN frame;
@@ -0,0 +1,37 @@
+-- Start, filed, form, frame and unit of every XBRL fact, and exact decimal values
+
+ALTER TABLE filing_assets
+	ADD COLUMN unit VARCHAR(32) NOT NULL DEFAULT '',
+	ADD COLUMN start DATE NULL,
+	ALTER COLUMN val TYPE NUMERIC(38, 10),
+	ADD COLUMN filed DATE NULL,
+	ADD COLUMN form VARCHAR(32) NOT NULL DEFAULT '',
+	ADD COLUMN frame VARCHAR(16) NULL;
+
+CREATE INDEX idx_filing_assets_filed ON filing_assets (filed);
+CREATE INDEX idx_filing_assets_form ON filing_assets (form);
+CREATE INDEX idx_filing_assets_frame ON filing_assets (frame);
+
+ALTER TABLE filing_common_stock_shares_outstanding
+	ADD COLUMN unit VARCHAR(32) NOT NULL DEFAULT '',
+	ADD COLUMN start DATE NULL,
+	ALTER COLUMN val TYPE NUMERIC(38, 10),
+	ADD COLUMN filed DATE NULL,
+	ADD COLUMN form VARCHAR(32) NOT NULL DEFAULT '',
+	ADD COLUMN frame VARCHAR(16) NULL;
+
+CREATE INDEX idx_filing_common_stock_shares_outstanding_filed ON filing_common_stock_shares_outstanding (filed);
+CREATE INDEX idx_filing_common_stock_shares_outstanding_form ON filing_common_stock_shares_outstanding (form);
+CREATE INDEX idx_filing_common_stock_shares_outstanding_frame ON filing_common_stock_shares_outstanding (frame);
+
+ALTER TABLE filing_entity_common_stock_shares_outstanding
+	ADD COLUMN unit VARCHAR(32) NOT NULL DEFAULT '',
+	ADD COLUMN start DATE NULL,
+	ALTER COLUMN val TYPE NUMERIC(38, 10),
+	ADD COLUMN filed DATE NULL,
+	ADD COLUMN form VARCHAR(32) NOT NULL DEFAULT '',
+	ADD COLUMN frame VARCHAR(16) NULL;
+
+CREATE INDEX idx_filing_entity_common_stock_shares_outstanding_filed ON filing_entity_common_stock_shares_outstanding (filed);
+CREATE INDEX idx_filing_entity_common_stock_shares_outstanding_form ON filing_entity_common_stock_shares_outstanding (form);
+CREATE INDEX idx_filing_entity_common_stock_shares_outstanding_frame ON filing_entity_common_stock_shares_outstanding (frame);
@@ -0,0 +1,15 @@
+-- Archive files that failed to parse, retried on every run until they parse again. last_seen_at is set by the upsert,
+-- PostgreSQL has no ON UPDATE CURRENT_TIMESTAMP.
+CREATE TABLE sec_parse_quarantine (
+	id BIGSERIAL PRIMARY KEY,
+	archive VARCHAR(16) NOT NULL,
+	file_name VARCHAR(64) NOT NULL,
+	field VARCHAR(255) NOT NULL,
+	item_index BIGINT NULL,
+	raw_value TEXT NULL,
+	message TEXT NOT NULL,
+	attempts INTEGER NOT NULL DEFAULT 1,
+	first_seen_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+	last_seen_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+	CONSTRAINT uq_sec_parse_quarantine_archive_file_name UNIQUE (archive, file_name)
+);
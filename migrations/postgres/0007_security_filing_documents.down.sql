@@ -0,0 +1,10 @@
+ALTER TABLE security_filing
+	DROP COLUMN act,
+	DROP COLUMN file_number,
+	DROP COLUMN film_number,
+	DROP COLUMN items,
+	DROP COLUMN size,
+	DROP COLUMN is_xbrl,
+	DROP COLUMN is_inline_xbrl,
+	DROP COLUMN primary_document,
+	DROP COLUMN primary_doc_description;
@@ -0,0 +1,12 @@
+-- The rest of the filing record of filings.recent. primary_document stays NULL on filings stored before this
+-- migration until the next sync fills it in.
+ALTER TABLE security_filing
+	ADD COLUMN act VARCHAR(8) NOT NULL DEFAULT '',
+	ADD COLUMN file_number VARCHAR(64) NOT NULL DEFAULT '',
+	ADD COLUMN film_number VARCHAR(64) NOT NULL DEFAULT '',
+	ADD COLUMN items VARCHAR(255) NOT NULL DEFAULT '',
+	ADD COLUMN size BIGINT NULL,
+	ADD COLUMN is_xbrl BOOLEAN NOT NULL DEFAULT FALSE,
+	ADD COLUMN is_inline_xbrl BOOLEAN NOT NULL DEFAULT FALSE,
+	ADD COLUMN primary_document VARCHAR(255) NULL,
+	ADD COLUMN primary_doc_description VARCHAR(255) NOT NULL DEFAULT '';
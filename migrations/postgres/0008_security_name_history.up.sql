@@ -0,0 +1,10 @@
+-- Former names of a security, from formerNames of the submissions JSON
+CREATE TABLE security_name_history (
+	id BIGSERIAL PRIMARY KEY,
+	security_cik VARCHAR(10) NOT NULL REFERENCES security (cik),
+	name VARCHAR(255) NOT NULL,
+	from_date DATE NULL,
+	to_date DATE NULL
+);
+
+CREATE INDEX idx_security_name_history_security_cik ON security_name_history (security_cik);
@@ -0,0 +1,10 @@
+DELETE FROM security_exchange_ticker WHERE valid_to IS NOT NULL;
+
+UPDATE security_exchange_ticker SET exchange = 'null' WHERE exchange IS NULL;
+
+DROP INDEX idx_security_exchange_ticker_ticker_period;
+
+ALTER TABLE security_exchange_ticker
+	DROP COLUMN valid_from,
+	DROP COLUMN valid_to,
+	ALTER COLUMN exchange SET NOT NULL;
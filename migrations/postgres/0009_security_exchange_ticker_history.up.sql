@@ -0,0 +1,11 @@
+-- Listing periods instead of delete-and-insert. valid_to is exclusive and NULL while the pair is listed. Rows from
+-- before this migration keep a NULL valid_from, meaning listed since an unknown date.
+ALTER TABLE security_exchange_ticker
+	ALTER COLUMN exchange DROP NOT NULL,
+	ADD COLUMN valid_from DATE NULL,
+	ADD COLUMN valid_to DATE NULL;
+
+CREATE INDEX idx_security_exchange_ticker_ticker_period ON security_exchange_ticker (ticker, valid_from, valid_to);
+
+-- Unknown exchanges used to be stored as the string "null"
+UPDATE security_exchange_ticker SET exchange = NULL WHERE exchange = 'null';
@@ -0,0 +1,8 @@
+DROP TABLE sec_submission_file_hash;
+
+CREATE TABLE sec_submission_file_hash (
+	id INTEGER PRIMARY KEY,
+	submission_file_name VARCHAR(64) NOT NULL,
+	hash VARCHAR(64) NOT NULL,
+	CONSTRAINT uq_sec_submission_file_hash_file_name UNIQUE (submission_file_name)
+);
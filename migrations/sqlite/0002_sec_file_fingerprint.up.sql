@@ -0,0 +1,14 @@
+-- Fingerprints now come from the zip central directory and are kept per archive. The old hashes cannot be compared
+-- with the new fingerprints, so they are dropped and every file is synchronized once more. SQLite cannot change
+-- constraints in place, the emptied table is created again instead.
+DROP TABLE sec_submission_file_hash;
+
+CREATE TABLE sec_submission_file_hash (
+	id INTEGER PRIMARY KEY,
+	archive VARCHAR(16) NOT NULL,
+	submission_file_name VARCHAR(64) NOT NULL,
+	crc32 BIGINT NOT NULL,
+	uncompressed_size BIGINT NOT NULL,
+	hash VARCHAR(64) NULL,
+	CONSTRAINT uq_sec_submission_file_hash_archive_file_name UNIQUE (archive, submission_file_name)
+);
@@ -0,0 +1,29 @@
+DROP INDEX idx_filing_assets_filed;
+DROP INDEX idx_filing_assets_form;
+DROP INDEX idx_filing_assets_frame;
+
+ALTER TABLE filing_assets DROP COLUMN unit;
+ALTER TABLE filing_assets DROP COLUMN start;
+ALTER TABLE filing_assets DROP COLUMN filed;
+ALTER TABLE filing_assets DROP COLUMN form;
+ALTER TABLE filing_assets DROP COLUMN frame;
+
+DROP INDEX idx_filing_common_stock_shares_outstanding_filed;
+DROP INDEX idx_filing_common_stock_shares_outstanding_form;
+DROP INDEX idx_filing_common_stock_shares_outstanding_frame;
+
+ALTER TABLE filing_common_stock_shares_outstanding DROP COLUMN unit;
+ALTER TABLE filing_common_stock_shares_outstanding DROP COLUMN start;
+ALTER TABLE filing_common_stock_shares_outstanding DROP COLUMN filed;
+ALTER TABLE filing_common_stock_shares_outstanding DROP COLUMN form;
+ALTER TABLE filing_common_stock_shares_outstanding DROP COLUMN frame;
+
+DROP INDEX idx_filing_entity_common_stock_shares_outstanding_filed;
+DROP INDEX idx_filing_entity_common_stock_shares_outstanding_form;
+DROP INDEX idx_filing_entity_common_stock_shares_outstanding_frame;
+
+ALTER TABLE filing_entity_common_stock_shares_outstanding DROP COLUMN unit;
+ALTER TABLE filing_entity_common_stock_shares_outstanding DROP COLUMN start;
+ALTER TABLE filing_entity_common_stock_shares_outstanding DROP COLUMN filed;
+ALTER TABLE filing_entity_common_stock_shares_outstanding DROP COLUMN form;
+ALTER TABLE filing_entity_common_stock_shares_outstanding DROP COLUMN frame;
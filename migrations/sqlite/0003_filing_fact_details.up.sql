@@ -0,0 +1,32 @@
+-- Start, filed, form, frame and unit of every XBRL fact, and exact decimal values. val already has NUMERIC affinity in
+-- SQLite, the closest it has to DECIMAL, and needs no change.
+
+ALTER TABLE filing_assets ADD COLUMN unit VARCHAR(32) NOT NULL DEFAULT '';
+ALTER TABLE filing_assets ADD COLUMN start DATE NULL;
+ALTER TABLE filing_assets ADD COLUMN filed DATE NULL;
+ALTER TABLE filing_assets ADD COLUMN form VARCHAR(32) NOT NULL DEFAULT '';
+ALTER TABLE filing_assets ADD COLUMN frame VARCHAR(16) NULL;
+
+CREATE INDEX idx_filing_assets_filed ON filing_assets (filed);
+CREATE INDEX idx_filing_assets_form ON filing_assets (form);
+CREATE INDEX idx_filing_assets_frame ON filing_assets (frame);
+
+ALTER TABLE filing_common_stock_shares_outstanding ADD COLUMN unit VARCHAR(32) NOT NULL DEFAULT '';
+ALTER TABLE filing_common_stock_shares_outstanding ADD COLUMN start DATE NULL;
+ALTER TABLE filing_common_stock_shares_outstanding ADD COLUMN filed DATE NULL;
+ALTER TABLE filing_common_stock_shares_outstanding ADD COLUMN form VARCHAR(32) NOT NULL DEFAULT '';
+ALTER TABLE filing_common_stock_shares_outstanding ADD COLUMN frame VARCHAR(16) NULL;
+
+CREATE INDEX idx_filing_common_stock_shares_outstanding_filed ON filing_common_stock_shares_outstanding (filed);
+CREATE INDEX idx_filing_common_stock_shares_outstanding_form ON filing_common_stock_shares_outstanding (form);
+CREATE INDEX idx_filing_common_stock_shares_outstanding_frame ON filing_common_stock_shares_outstanding (frame);
+
+ALTER TABLE filing_entity_common_stock_shares_outstanding ADD COLUMN unit VARCHAR(32) NOT NULL DEFAULT '';
+ALTER TABLE filing_entity_common_stock_shares_outstanding ADD COLUMN start DATE NULL;
+ALTER TABLE filing_entity_common_stock_shares_outstanding ADD COLUMN filed DATE NULL;
+ALTER TABLE filing_entity_common_stock_shares_outstanding ADD COLUMN form VARCHAR(32) NOT NULL DEFAULT '';
+ALTER TABLE filing_entity_common_stock_shares_outstanding ADD COLUMN frame VARCHAR(16) NULL;
+
+CREATE INDEX idx_filing_entity_common_stock_shares_outstanding_filed ON filing_entity_common_stock_shares_outstanding (filed);
+CREATE INDEX idx_filing_entity_common_stock_shares_outstanding_form ON filing_entity_common_stock_shares_outstanding (form);
+CREATE INDEX idx_filing_entity_common_stock_shares_outstanding_frame ON filing_entity_common_stock_shares_outstanding (frame);
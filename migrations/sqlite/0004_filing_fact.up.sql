@@ -0,0 +1,24 @@
+-- Every configured XBRL concept, from any taxonomy and in any unit
+CREATE TABLE filing_fact (
+	id INTEGER PRIMARY KEY,
+	security_filing_accession_number VARCHAR(20) NOT NULL,
+	canonical_concept VARCHAR(255) NOT NULL,
+	taxonomy VARCHAR(64) NOT NULL,
+	concept VARCHAR(255) NOT NULL,
+	unit VARCHAR(32) NOT NULL,
+	start DATE NULL,
+	"end" DATE NOT NULL,
+	fp VARCHAR(8) NOT NULL,
+	fy INTEGER NOT NULL,
+	val NUMERIC NOT NULL,
+	filed DATE NOT NULL,
+	form VARCHAR(32) NOT NULL,
+	frame VARCHAR(16) NULL
+);
+
+CREATE INDEX idx_filing_fact_identity ON filing_fact (security_filing_accession_number, taxonomy, concept, unit, start, "end");
+CREATE INDEX idx_filing_fact_taxonomy_concept ON filing_fact (taxonomy, concept);
+CREATE INDEX idx_filing_fact_canonical_concept ON filing_fact (canonical_concept);
+CREATE INDEX idx_filing_fact_filed ON filing_fact (filed);
+CREATE INDEX idx_filing_fact_form ON filing_fact (form);
+CREATE INDEX idx_filing_fact_frame ON filing_fact (frame);
@@ -0,0 +1 @@
+DROP TABLE IF EXISTS sec_parse_quarantine;
@@ -0,0 +1,14 @@
+ALTER TABLE security DROP COLUMN mailing_street1;
+ALTER TABLE security DROP COLUMN mailing_city;
+ALTER TABLE security DROP COLUMN mailing_country;
+ALTER TABLE security DROP COLUMN mailing_state;
+ALTER TABLE security DROP COLUMN mailing_zip;
+ALTER TABLE security DROP COLUMN category;
+ALTER TABLE security DROP COLUMN fiscal_year_end;
+ALTER TABLE security DROP COLUMN flags;
+ALTER TABLE security DROP COLUMN insider_transaction_for_issuer_exists;
+ALTER TABLE security DROP COLUMN insider_transaction_for_owner_exists;
+ALTER TABLE security DROP COLUMN investor_website;
+ALTER TABLE security DROP COLUMN owner_org;
+ALTER TABLE security DROP COLUMN sic_description;
+ALTER TABLE security DROP COLUMN state_of_incorporation;
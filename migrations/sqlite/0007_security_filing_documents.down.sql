@@ -0,0 +1,9 @@
+ALTER TABLE security_filing DROP COLUMN act;
+ALTER TABLE security_filing DROP COLUMN file_number;
+ALTER TABLE security_filing DROP COLUMN film_number;
+ALTER TABLE security_filing DROP COLUMN items;
+ALTER TABLE security_filing DROP COLUMN size;
+ALTER TABLE security_filing DROP COLUMN is_xbrl;
+ALTER TABLE security_filing DROP COLUMN is_inline_xbrl;
+ALTER TABLE security_filing DROP COLUMN primary_document;
+ALTER TABLE security_filing DROP COLUMN primary_doc_description;
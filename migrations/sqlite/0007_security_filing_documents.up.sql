@@ -0,0 +1,11 @@
+-- The rest of the filing record of filings.recent. primary_document stays NULL on filings stored before this
+-- migration until the next sync fills it in.
+ALTER TABLE security_filing ADD COLUMN act VARCHAR(8) NOT NULL DEFAULT '';
+ALTER TABLE security_filing ADD COLUMN file_number VARCHAR(64) NOT NULL DEFAULT '';
+ALTER TABLE security_filing ADD COLUMN film_number VARCHAR(64) NOT NULL DEFAULT '';
+ALTER TABLE security_filing ADD COLUMN items VARCHAR(255) NOT NULL DEFAULT '';
+ALTER TABLE security_filing ADD COLUMN size BIGINT NULL;
+ALTER TABLE security_filing ADD COLUMN is_xbrl BOOLEAN NOT NULL DEFAULT FALSE;
+ALTER TABLE security_filing ADD COLUMN is_inline_xbrl BOOLEAN NOT NULL DEFAULT FALSE;
+ALTER TABLE security_filing ADD COLUMN primary_document VARCHAR(255) NULL;
+ALTER TABLE security_filing ADD COLUMN primary_doc_description VARCHAR(255) NOT NULL DEFAULT '';
@@ -0,0 +1,16 @@
+CREATE TABLE security_exchange_ticker_current (
+	id INTEGER PRIMARY KEY,
+	security_cik VARCHAR(10) NOT NULL REFERENCES security (cik),
+	exchange VARCHAR(64) NOT NULL,
+	ticker VARCHAR(32) NOT NULL
+);
+
+INSERT INTO security_exchange_ticker_current (id, security_cik, exchange, ticker)
+SELECT id, security_cik, COALESCE(exchange, 'null'), ticker FROM security_exchange_ticker WHERE valid_to IS NULL;
+
+DROP TABLE security_exchange_ticker;
+
+ALTER TABLE security_exchange_ticker_current RENAME TO security_exchange_ticker;
+
+CREATE INDEX idx_security_exchange_ticker_security_cik ON security_exchange_ticker (security_cik);
+CREATE INDEX idx_security_exchange_ticker_ticker ON security_exchange_ticker (ticker);
@@ -0,0 +1,23 @@
+-- Listing periods instead of delete-and-insert. valid_to is exclusive and NULL while the pair is listed. Rows from
+-- before this migration keep a NULL valid_from, meaning listed since an unknown date. SQLite cannot drop NOT NULL
+-- from a column, so the table is rebuilt.
+CREATE TABLE security_exchange_ticker_history (
+	id INTEGER PRIMARY KEY,
+	security_cik VARCHAR(10) NOT NULL REFERENCES security (cik),
+	exchange VARCHAR(64) NULL,
+	ticker VARCHAR(32) NOT NULL,
+	valid_from DATE NULL,
+	valid_to DATE NULL
+);
+
+-- Unknown exchanges used to be stored as the string "null"
+INSERT INTO security_exchange_ticker_history (id, security_cik, exchange, ticker)
+SELECT id, security_cik, NULLIF(exchange, 'null'), ticker FROM security_exchange_ticker;
+
+DROP TABLE security_exchange_ticker;
+
+ALTER TABLE security_exchange_ticker_history RENAME TO security_exchange_ticker;
+
+CREATE INDEX idx_security_exchange_ticker_security_cik ON security_exchange_ticker (security_cik);
+CREATE INDEX idx_security_exchange_ticker_ticker ON security_exchange_ticker (ticker);
+CREATE INDEX idx_security_exchange_ticker_ticker_period ON security_exchange_ticker (ticker, valid_from, valid_to);
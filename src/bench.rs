@@ -0,0 +1,169 @@
+use std::alloc::{ GlobalAlloc, Layout, System };
+use std::path::PathBuf;
+use std::sync::atomic::{ AtomicUsize, Ordering };
+use std::time::{ Duration, Instant };
+
+use crate::log_info;
+use crate::handler::file::zip::HandlerFileCompanyfactsZip;
+use crate::schema::{ FactConcept, FileFingerprint };
+
+
+/**
+* Global allocator that tracks live and peak heap bytes. Only compiled in with `--features bench` so normal builds
+* keep the plain system allocator.
+*/
+struct CountingAllocator;
+
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator
+{
+	unsafe fn alloc(&self, layout: Layout) -> *mut u8
+	{
+		let ptr: *mut u8 = System.alloc(layout);
+
+		if !ptr.is_null()
+		{
+			let allocated: usize = ALLOCATED.fetch_add(layout.size(), Ordering::Relaxed) + layout.size();
+
+			PEAK.fetch_max(allocated, Ordering::Relaxed);
+		}
+
+		ptr
+	}
+
+	unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout)
+	{
+		System.dealloc(ptr, layout);
+
+		ALLOCATED.fetch_sub(layout.size(), Ordering::Relaxed);
+	}
+
+	unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8
+	{
+		let new_ptr: *mut u8 = System.realloc(ptr, layout, new_size);
+
+		if !new_ptr.is_null()
+		{
+			if new_size >= layout.size()
+			{
+				let grown: usize = new_size - layout.size();
+
+				let allocated: usize = ALLOCATED.fetch_add(grown, Ordering::Relaxed) + grown;
+
+				PEAK.fetch_max(allocated, Ordering::Relaxed);
+			}
+			else
+			{
+				ALLOCATED.fetch_sub(layout.size() - new_size, Ordering::Relaxed);
+			}
+		}
+
+		new_ptr
+	}
+}
+
+
+/**
+* Time and peak heap of one way of parsing, summed over the benchmarked files
+*/
+#[derive(Default)]
+struct BenchResult
+{
+	elapsed: Duration,
+	peak_bytes_max: usize,
+	peak_bytes_total: usize,
+}
+
+impl BenchResult
+{
+	/**
+	* Run `f` once and record its time and the heap it needed above what was live before it started
+	*/
+	fn measure<T>(&mut self, f: impl FnOnce() -> Result<T, Box<dyn std::error::Error>>) -> Result<(), Box<dyn std::error::Error>>
+	{
+		let baseline: usize = ALLOCATED.load(Ordering::Relaxed);
+
+		PEAK.store(baseline, Ordering::Relaxed);
+
+		let start: Instant = Instant::now();
+
+		let result: T = f()?;
+
+		self.elapsed += start.elapsed();
+
+		drop(result);
+
+		let peak_bytes: usize = PEAK.load(Ordering::Relaxed) - baseline;
+
+		self.peak_bytes_max = self.peak_bytes_max.max(peak_bytes);
+		self.peak_bytes_total += peak_bytes;
+
+		Ok(())
+	}
+
+	fn print(&self, name: &str, files: usize)
+	{
+		const BYTES_PER_MB: f64 = 1024.0 * 1024.0;
+
+		println!(
+			"{:<10} total {:>8.2}s  per CIK {:>8.2}ms  peak heap per CIK: avg {:>8.2} MB, max {:>8.2} MB",
+			name,
+			self.elapsed.as_secs_f64(),
+			self.elapsed.as_secs_f64() * 1000.0 / files.max(1) as f64,
+			self.peak_bytes_total as f64 / files.max(1) as f64 / BYTES_PER_MB,
+			self.peak_bytes_max as f64 / BYTES_PER_MB
+		);
+	}
+}
+
+
+/**
+* Compare building a full `serde_json::Value` tree per CIK against the streaming typed parse on the largest files of a
+* companyfacts.zip
+* @param path_companyfacts_zip {PathBuf} Path to a companyfacts.zip
+* @param files {usize} How many of the largest files to parse
+* @param fact_concepts {&[FactConcept]} Concepts the streaming parse extracts
+*/
+pub fn run(path_companyfacts_zip: PathBuf, files: usize, fact_concepts: &[FactConcept]) -> Result<(), Box<dyn std::error::Error>>
+{
+	let mut handler_file_companyfacts_zip = HandlerFileCompanyfactsZip::new(path_companyfacts_zip)?;
+
+	let mut file_names_to_fingerprints: Vec<(String, FileFingerprint)> = handler_file_companyfacts_zip
+		.compute_file_names_to_fingerprints(false)?
+		.into_iter()
+		.collect();
+
+	file_names_to_fingerprints.sort_by_key(|(_, f)| std::cmp::Reverse(f.size));
+
+	file_names_to_fingerprints.truncate(files);
+
+	log_info!("Benchmarking the {} largest files of companyfacts.zip..", file_names_to_fingerprints.len());
+
+	let mut value_tree: BenchResult = BenchResult::default();
+	let mut streaming: BenchResult = BenchResult::default();
+
+	for (file_name, _) in &file_names_to_fingerprints
+	{
+		value_tree.measure(|| handler_file_companyfacts_zip.load_json_from_file(file_name))?;
+
+		streaming.measure(|| Ok(handler_file_companyfacts_zip.extract_data(file_name, fact_concepts)?))?;
+	}
+
+	let uncompressed_bytes: u64 = file_names_to_fingerprints.iter().map(|(_, f)| f.size).sum();
+
+	println!(
+		"{} files, {:.2} MB uncompressed JSON",
+		file_names_to_fingerprints.len(),
+		uncompressed_bytes as f64 / 1024.0 / 1024.0
+	);
+
+	value_tree.print("value tree", file_names_to_fingerprints.len());
+	streaming.print("streaming", file_names_to_fingerprints.len());
+
+	Ok(())
+}
@@ -0,0 +1,551 @@
+use serde::{ Deserialize, Serialize };
+use std::path::{ Path, PathBuf };
+
+use crate::logger::LogLevel;
+use crate::schema::FactConcept;
+
+use crate::{ log_warn };
+
+
+/**
+* Where downloaded archives and their sidecar files are kept
+*/
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ConfigData
+{
+	pub dir: PathBuf,
+}
+
+impl Default for ConfigData
+{
+	fn default() -> Self
+	{
+		Self
+		{
+			dir: PathBuf::from(".tmp"),
+		}
+	}
+}
+
+
+/**
+* SEC endpoints and how we talk to them
+*/
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ConfigSec
+{
+	/// SEC requires a User-Agent identifying the requester with a contact email
+	pub user_agent: String,
+	pub request_url_companyfacts_zip: String,
+	pub request_url_submissions_zip: String,
+	pub request_url_data: String,
+	pub connect_timeout_seconds: u64,
+	pub read_timeout_seconds: u64,
+	pub requests_per_second: f64,
+	pub max_retries: u32,
+	/// Only used for archives the server never sent ETag / Last-Modified for
+	pub staleness_hours: u64,
+}
+
+impl Default for ConfigSec
+{
+	fn default() -> Self
+	{
+		Self
+		{
+			user_agent: String::new(),
+			request_url_companyfacts_zip: "https://www.sec.gov/Archives/edgar/daily-index/xbrl/companyfacts.zip".to_string(),
+			request_url_submissions_zip: "https://www.sec.gov/Archives/edgar/daily-index/bulkdata/submissions.zip".to_string(),
+			request_url_data: "https://data.sec.gov".to_string(),
+			connect_timeout_seconds: 30,
+			read_timeout_seconds: 60,
+			requests_per_second: 10.0,
+			max_retries: 5,
+			staleness_hours: 24,
+		}
+	}
+}
+
+
+/**
+* Local time of day the daily synchronization runs at
+*/
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ConfigSchedule
+{
+	pub hour: u32,
+	pub minute: u32,
+}
+
+impl Default for ConfigSchedule
+{
+	fn default() -> Self
+	{
+		Self
+		{
+			hour: 4,
+			minute: 0,
+		}
+	}
+}
+
+
+/**
+* How the synchronizer decides which files changed
+*/
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ConfigSync
+{
+	/// Also compare a SHA-256 of every file instead of trusting the zip CRC32 and size alone
+	pub strict_sha256: bool,
+}
+
+
+/**
+* XBRL concepts extracted from companyfacts into the filing_fact table
+*/
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ConfigFacts
+{
+	pub concepts: Vec<FactConcept>,
+}
+
+impl Default for ConfigFacts
+{
+	fn default() -> Self
+	{
+		Self
+		{
+			concepts: vec![
+				FactConcept::new("us-gaap", "Assets", None).with_equivalents(&["ifrs-full:Assets"]),
+				FactConcept::new("us-gaap", "CommonStockSharesOutstanding", None).with_equivalents(
+					&["ifrs-full:NumberOfSharesOutstanding"]
+				),
+				FactConcept::new("dei", "EntityCommonStockSharesOutstanding", None),
+			],
+		}
+	}
+}
+
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ConfigDatabase
+{
+	pub url: Option<String>,
+	pub max_connections: u32,
+	pub min_connections: u32,
+	pub acquire_timeout_seconds: u64,
+}
+
+impl Default for ConfigDatabase
+{
+	fn default() -> Self
+	{
+		Self
+		{
+			url: None,
+			max_connections: 10,
+			min_connections: 0,
+			acquire_timeout_seconds: 30,
+		}
+	}
+}
+
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ConfigLog
+{
+	pub level: String,
+}
+
+impl Default for ConfigLog
+{
+	fn default() -> Self
+	{
+		Self
+		{
+			level: std::env::var("LOG_LEVEL").unwrap_or_else(|_| "info".to_string()),
+		}
+	}
+}
+
+
+/**
+* Application configuration, read from a TOML file and overridden by `APP__<SECTION>__<KEY>` environment variables
+* (e.g. `APP__DATABASE__URL`, `APP__SEC__USER_AGENT`)
+*/
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config
+{
+	pub data: ConfigData,
+	pub sec: ConfigSec,
+	pub schedule: ConfigSchedule,
+	pub sync: ConfigSync,
+	pub facts: ConfigFacts,
+	pub database: ConfigDatabase,
+	pub log: ConfigLog,
+}
+
+
+impl Config
+{
+	pub const DEFAULT_PATH: &'static str = "config.toml";
+
+	const ENV_PREFIX: &'static str = "APP__";
+	const ENV_CONFIG_PATH: &'static str = "APP__CONFIG";
+
+
+	/**
+	* Every configuration key with a value of its type, to look up what an environment variable overrides
+	*/
+	fn env_template() -> toml::Table
+	{
+		let mut config: Self = Self::default();
+
+		// Unset options are left out of the serialized table, give them a value so they count as known keys
+		config.database.url = Some(String::new());
+
+		toml::Table::try_from(config).unwrap_or_default()
+	}
+
+	/**
+	* Look up the value at the nested `path` of `table`
+	*/
+	fn lookup_env_value<'a>(table: &'a toml::Table, path: &[String]) -> Option<&'a toml::Value>
+	{
+		match path
+		{
+			[] => None,
+			[leaf] => table.get(leaf),
+			[section, rest @ ..] => Self::lookup_env_value(table.get(section)?.as_table()?, rest),
+		}
+	}
+
+	/**
+	* Interpret an environment variable value as the TOML type of the key it overrides. Anything that does not parse
+	* as that type is kept as a string, so deserialization reports it against the key.
+	* @param raw {&str} Value of the environment variable
+	* @param template {&toml::Value} Default value of the overridden key
+	*/
+	fn parse_env_value(raw: &str, template: &toml::Value) -> toml::Value
+	{
+		let parsed: Option<toml::Value> = match template
+		{
+			toml::Value::Integer(_) => raw.parse::<i64>().ok().map(toml::Value::Integer),
+			toml::Value::Float(_) => raw.parse::<f64>().ok().map(toml::Value::Float),
+			toml::Value::Boolean(_) => raw.parse::<bool>().ok().map(toml::Value::Boolean),
+			_ => None,
+		};
+
+		parsed.unwrap_or_else(|| toml::Value::String(raw.to_string()))
+	}
+
+	/**
+	* Set `value` at the nested `path` of `table`, creating sections as needed
+	*/
+	fn insert_env_value(table: &mut toml::Table, path: &[String], value: toml::Value)
+	{
+		match path
+		{
+			[] => {},
+			[leaf] =>
+			{
+				table.insert(leaf.clone(), value);
+			},
+			[section, rest @ ..] =>
+			{
+				let entry = table.entry(section.clone()).or_insert_with(|| toml::Value::Table(toml::Table::new()));
+
+				if !entry.is_table()
+				{
+					*entry = toml::Value::Table(toml::Table::new());
+				}
+
+				if let toml::Value::Table(t) = entry
+				{
+					Self::insert_env_value(t, rest, value);
+				}
+			},
+		}
+	}
+
+	/**
+	* Apply `APP__<SECTION>__<KEY>` environment variables on top of the parsed file. Variables that match no key are
+	* skipped with a warning rather than failing startup.
+	*/
+	fn apply_env_overrides(table: &mut toml::Table, vars: impl Iterator<Item = (String, String)>)
+	{
+		let template: toml::Table = Self::env_template();
+
+		for (key, value) in vars
+		{
+			if key == Self::ENV_CONFIG_PATH
+			{
+				continue;
+			}
+
+			let Some(path) = key.strip_prefix(Self::ENV_PREFIX) else { continue };
+
+			let path: Vec<String> = path.split("__").map(|p| p.to_ascii_lowercase()).collect();
+
+			let Some(template_value) = Self::lookup_env_value(&template, &path) else
+			{
+				log_warn!("Ignoring environment variable {}: it does not match any configuration key", key);
+
+				continue;
+			};
+
+			Self::insert_env_value(table, &path, Self::parse_env_value(&value, template_value));
+		}
+	}
+
+	/**
+	* Load the configuration. The file is `path` if given, else `APP__CONFIG`, else `config.toml` when present.
+	* @param path {Option<&Path>} Explicit config file path
+	*/
+	pub fn load(path: Option<&Path>) -> Result<Self, Box<dyn std::error::Error>>
+	{
+		let path: Option<PathBuf> = match path
+		{
+			Some(path) => Some(path.to_path_buf()),
+			None => match std::env::var(Self::ENV_CONFIG_PATH)
+			{
+				Ok(path) => Some(PathBuf::from(path)),
+				Err(_) => Some(PathBuf::from(Self::DEFAULT_PATH)).filter(|p| p.exists()),
+			},
+		};
+
+		let mut table: toml::Table = match &path
+		{
+			Some(path) =>
+			{
+				let contents: String = std::fs::read_to_string(path).map_err(
+					|e| format!("Failed to read config file {}: {}", path.display(), e)
+				)?;
+
+				contents.parse::<toml::Table>().map_err(
+					|e| format!("Failed to parse config file {}: {}", path.display(), e)
+				)?
+			},
+			None => toml::Table::new(),
+		};
+
+		Self::apply_env_overrides(&mut table, std::env::vars());
+
+		let config: Self = toml::Value::Table(table).try_into().map_err(
+			|e| format!("Invalid configuration: {}", e)
+		)?;
+
+		config.validate()?;
+
+		Ok(config)
+	}
+
+	/**
+	* Check the configuration for values that would only fail later at runtime
+	*/
+	pub fn validate(&self) -> Result<(), Box<dyn std::error::Error>>
+	{
+		let mut errors: Vec<String> = Vec::new();
+
+		for (name, url) in [
+			("sec.request_url_companyfacts_zip", &self.sec.request_url_companyfacts_zip),
+			("sec.request_url_submissions_zip", &self.sec.request_url_submissions_zip),
+			("sec.request_url_data", &self.sec.request_url_data),
+		]
+		{
+			if !url.starts_with("http://") && !url.starts_with("https://")
+			{
+				errors.push(format!("{} must be an http(s) URL, got \"{}\"", name, url));
+			}
+		}
+
+		if self.sec.requests_per_second <= 0.0 || self.sec.requests_per_second > 10.0
+		{
+			errors.push("sec.requests_per_second must be greater than 0 and at most 10 (SEC fair-access limit)".to_string());
+		}
+
+		if self.sec.connect_timeout_seconds == 0 || self.sec.read_timeout_seconds == 0
+		{
+			errors.push("sec.connect_timeout_seconds and sec.read_timeout_seconds must be greater than 0".to_string());
+		}
+
+		if self.schedule.hour > 23 || self.schedule.minute > 59
+		{
+			errors.push(format!("schedule {:02}:{:02} is not a valid time of day", self.schedule.hour, self.schedule.minute));
+		}
+
+		for c in &self.facts.concepts
+		{
+			if c.taxonomy.is_empty() || c.concept.is_empty() || c.unit.as_deref() == Some("")
+			{
+				errors.push(format!("facts.concepts entry {:?} must set taxonomy and concept, and unit if present", c));
+			}
+
+			for e in &c.equivalents
+			{
+				if !matches!(e.split_once(':'), Some((t, n)) if !t.is_empty() && !n.is_empty())
+				{
+					errors.push(format!("facts.concepts equivalent \"{}\" of {} must be \"taxonomy:concept\"", e, c.canonical()));
+				}
+			}
+		}
+
+		if self.database.max_connections == 0 || self.database.min_connections > self.database.max_connections
+		{
+			errors.push("database.max_connections must be at least 1 and at least database.min_connections".to_string());
+		}
+
+		if LogLevel::from_str(&self.log.level).is_none()
+		{
+			errors.push(format!("log.level \"{}\" is not one of error, warn, info, debug, superdebug, ultradebug", self.log.level));
+		}
+
+		if !errors.is_empty()
+		{
+			return Err(format!("Invalid configuration:\n  - {}", errors.join("\n  - ")).into());
+		}
+
+		Ok(())
+	}
+
+	/**
+	* Check the settings only needed when talking to the SEC, so offline runs do not require them
+	*/
+	pub fn validate_sec(&self) -> Result<(), Box<dyn std::error::Error>>
+	{
+		if self.sec.user_agent.trim().is_empty()
+		{
+			return Err(
+				"Invalid configuration:\n  - sec.user_agent must be set to identify you to the SEC, e.g. \"Company Name admin@example.com\"".into()
+			);
+		}
+
+		Ok(())
+	}
+
+	/**
+	* The effective configuration as TOML, with the database password masked
+	*/
+	pub fn to_display_string(&self) -> Result<String, Box<dyn std::error::Error>>
+	{
+		let mut config: Self = self.clone();
+
+		if let Some(url) = &config.database.url
+		{
+			config.database.url = Some(Self::mask_url_password(url));
+		}
+
+		Ok(toml::to_string_pretty(&config)?)
+	}
+
+	fn mask_url_password(url: &str) -> String
+	{
+		let Some((scheme, rest)) = url.split_once("://") else { return url.to_string() };
+
+		let Some((credentials, host)) = rest.rsplit_once('@') else { return url.to_string() };
+
+		match credentials.split_once(':')
+		{
+			Some((user, _)) => format!("{}://{}:****@{}", scheme, user, host),
+			None => url.to_string(),
+		}
+	}
+}
+
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+
+
+	fn config_from_env(vars: &[(&str, &str)]) -> Result<Config, toml::de::Error>
+	{
+		let mut table: toml::Table = toml::Table::new();
+
+		Config::apply_env_overrides(&mut table, vars.iter().map(|(k, v)| (k.to_string(), v.to_string())));
+
+		toml::Value::Table(table).try_into()
+	}
+
+	#[test]
+	fn env_overrides_are_parsed_as_the_type_of_their_key()
+	{
+		let config: Config = config_from_env(
+			&[
+				("APP__SEC__MAX_RETRIES", "7"),
+				("APP__SEC__REQUESTS_PER_SECOND", "4"),
+				("APP__SYNC__STRICT_SHA256", "true"),
+				("APP__DATABASE__URL", "sqlite://test.db"),
+			]
+		).unwrap();
+
+		assert_eq!(config.sec.max_retries, 7);
+		assert_eq!(config.sec.requests_per_second, 4.0);
+		assert!(config.sync.strict_sha256);
+		assert_eq!(config.database.url.as_deref(), Some("sqlite://test.db"));
+	}
+
+	#[test]
+	fn env_overrides_of_string_keys_stay_strings()
+	{
+		let config: Config = config_from_env(
+			&[
+				("APP__DATA__DIR", "2024"),
+				("APP__SEC__USER_AGENT", "12345"),
+				("APP__LOG__LEVEL", "true"),
+			]
+		).unwrap();
+
+		assert_eq!(config.data.dir, PathBuf::from("2024"));
+		assert_eq!(config.sec.user_agent, "12345");
+		assert_eq!(config.log.level, "true");
+	}
+
+	#[test]
+	fn env_overrides_of_unknown_keys_are_skipped()
+	{
+		let config: Config = config_from_env(
+			&[
+				("APP__FOO__BAR", "1"),
+				("APP__SEC__NOT_A_KEY", "1"),
+				("APP__CONFIG", "other.toml"),
+				("APP__SCHEDULE__HOUR", "6"),
+			]
+		).unwrap();
+
+		assert_eq!(config.schedule.hour, 6);
+	}
+
+	#[test]
+	fn env_overrides_that_do_not_parse_as_their_type_are_rejected()
+	{
+		assert!(config_from_env(&[("APP__SEC__MAX_RETRIES", "many")]).is_err());
+	}
+
+	#[test]
+	fn a_schedule_outside_the_day_is_rejected()
+	{
+		let mut config: Config = config_from_env(&[("APP__SCHEDULE__HOUR", "24")]).unwrap();
+
+		assert!(config.validate().unwrap_err().to_string().contains("schedule 24:00"));
+
+		config.schedule.hour = 23;
+		config.schedule.minute = 60;
+
+		assert!(config.validate().is_err());
+
+		config.schedule.minute = 59;
+
+		assert!(config.validate().is_ok());
+	}
+}
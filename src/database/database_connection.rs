@@ -1,42 +1,36 @@
-use sqlx::MySqlPool;
-
+use crate::config::ConfigDatabase;
+use crate::database::storage::{ self, Storage };
 use crate::{ log_info };
 
 
 pub struct DatabaseConnection
 {
-	pool: MySqlPool,
+	storage: Box<dyn Storage>,
 }
 
 
 impl DatabaseConnection
 {
-	pub async fn new() -> Result<Self, Box<dyn std::error::Error>>
+	pub async fn new(config_database: &ConfigDatabase) -> Result<Self, Box<dyn std::error::Error>>
 	{
-		let database_url = std::env::var("APP__DATABASE__URL").map_err(
-			|_| "APP__DATABASE__URL environment variable not set"
-		)?;
-
-		log_info!("Creating connection to Database..");
-
-		let pool = MySqlPool::connect(&database_url).await?;
+		let storage = storage::connect(config_database).await?;
 
 		log_info!("Database connection established");
 
-		Ok(Self { pool })
+		Ok(Self { storage })
 	}
 
 	pub async fn close(&self) -> Result<(), Box<dyn std::error::Error>>
 	{
 		log_info!("Closing connection to Database..");
 
-		self.pool.close().await;
+		self.storage.close().await;
 
 		Ok(())
 	}
 
-	pub fn pool(&self) -> &MySqlPool
+	pub fn storage(&self) -> &dyn Storage
 	{
-		&self.pool
+		self.storage.as_ref()
 	}
 }
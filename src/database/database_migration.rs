@@ -0,0 +1,184 @@
+use std::sync::Arc;
+use sqlx::migrate::AppliedMigration;
+
+use super::database_connection::DatabaseConnection;
+use crate::{ log_info };
+
+
+/**
+* One embedded migration and whether it was applied to the database
+*/
+#[derive(Debug)]
+pub struct MigrationStatus
+{
+	pub version: i64,
+	pub description: String,
+	pub applied: bool,
+	pub checksum_matches: bool,
+}
+
+
+pub struct DatabaseMigration
+{
+	db_connection: Arc<DatabaseConnection>,
+}
+
+
+impl DatabaseMigration
+{
+	pub fn new(db_connection: Arc<DatabaseConnection>) -> Self
+	{
+		Self { db_connection }
+	}
+
+	/**
+	* Schema version this binary was built for, i.e. the latest migration embedded for the connected backend
+	*/
+	pub fn expected_version(&self) -> i64
+	{
+		self.db_connection.storage().migrator().iter().filter(
+			|m| !m.migration_type.is_down_migration()
+		).map(
+			|m| m.version
+		).max().unwrap_or(0)
+	}
+
+	/**
+	* Migrations recorded in the database. Fails if a migration was left half-applied.
+	*/
+	async fn applied_migrations(&self) -> Result<Vec<AppliedMigration>, Box<dyn std::error::Error>>
+	{
+		self.db_connection.storage().migrations_applied().await
+	}
+
+	/**
+	* Apply every pending migration
+	*/
+	pub async fn up(&self) -> Result<(), Box<dyn std::error::Error>>
+	{
+		log_info!("Migrating database schema up to version {}..", self.expected_version());
+
+		self.db_connection.storage().migrations_run().await
+	}
+
+	/**
+	* Revert the applied migrations above `target`, by default only the latest one. Returns the version the schema is
+	* at afterwards.
+	* @param target {Option<i64>} Version to revert to, 0 reverts everything
+	*/
+	pub async fn down(&self, target: Option<i64>) -> Result<i64, Box<dyn std::error::Error>>
+	{
+		let mut applied_versions: Vec<i64> = self.applied_migrations().await?.iter().map(|m| m.version).collect();
+
+		applied_versions.sort();
+
+		let target: i64 = match target
+		{
+			Some(target) => target,
+			None =>
+			{
+				match applied_versions.len()
+				{
+					0 => return Err("No migration has been applied, nothing to revert".into()),
+					1 => 0,
+					n => applied_versions[n - 2],
+				}
+			},
+		};
+
+		log_info!("Migrating database schema down to version {}..", target);
+
+		self.db_connection.storage().migrations_undo(target).await?;
+
+		Ok(target)
+	}
+
+	/**
+	* Every embedded migration with whether it was applied, plus applied migrations this binary does not know
+	*/
+	pub async fn status(&self) -> Result<Vec<MigrationStatus>, Box<dyn std::error::Error>>
+	{
+		let applied_migrations: Vec<AppliedMigration> = self.applied_migrations().await?;
+
+		let mut migration_statuses: Vec<MigrationStatus> = self.db_connection.storage().migrator().iter().filter(
+			|m| !m.migration_type.is_down_migration()
+		).map(
+			|m|
+			{
+				let applied: Option<&AppliedMigration> = applied_migrations.iter().find(|a| a.version == m.version);
+
+				MigrationStatus
+				{
+					version: m.version,
+					description: m.description.to_string(),
+					applied: applied.is_some(),
+					checksum_matches: applied.is_none_or(|a| a.checksum == m.checksum),
+				}
+			}
+		).collect();
+
+		for applied in &applied_migrations
+		{
+			if !self.db_connection.storage().migrator().version_exists(applied.version)
+			{
+				migration_statuses.push(
+					MigrationStatus
+					{
+						version: applied.version,
+						description: String::from("(unknown to this binary)"),
+						applied: true,
+						checksum_matches: false,
+					}
+				);
+			}
+		}
+
+		Ok(migration_statuses)
+	}
+
+	/**
+	* Fail unless the database schema is exactly at the version this binary expects
+	*/
+	pub async fn check_version(&self) -> Result<(), Box<dyn std::error::Error>>
+	{
+		let expected_version: i64 = self.expected_version();
+
+		let statuses: Vec<MigrationStatus> = self.status().await?;
+
+		let applied_version: i64 = statuses.iter().filter(|s| s.applied).map(|s| s.version).max().unwrap_or(0);
+
+		if applied_version > expected_version
+		{
+			return Err(
+				format!(
+					"Database schema is at version {} but this binary expects {}. Upgrade the binary",
+					applied_version,
+					expected_version
+				).into()
+			);
+		}
+
+		if let Some(status) = statuses.iter().find(|s| !s.applied)
+		{
+			return Err(
+				format!(
+					"Database schema is missing migration {} ({}), this binary expects version {}. Run `migrate up`",
+					status.version,
+					status.description,
+					expected_version
+				).into()
+			);
+		}
+
+		if let Some(status) = statuses.iter().find(|s| !s.checksum_matches)
+		{
+			return Err(
+				format!("Migration {} ({}) was changed after it was applied", status.version, status.description).into()
+			);
+		}
+
+		log_info!("Database schema is at version {}", applied_version);
+
+		Ok(())
+	}
+}
@@ -1,9 +1,12 @@
 pub mod database_connection;
+pub mod database_migration;
+pub mod storage;
 pub mod table_asset;
-pub mod table_filing_assets;
-pub mod table_filing_common_stock_shares_outstanding;
-pub mod table_filing_entity_common_stock_shares_outstanding;
+pub mod table_filing_fact;
+pub mod table_filing_legacy;
+pub mod table_sec_parse_quarantine;
 pub mod table_sec_submission_file_hash;
 pub mod table_security;
 pub mod table_security_exchange_ticker;
 pub mod table_security_filing;
+pub mod table_security_name_history;
@@ -0,0 +1,293 @@
+pub mod storage_mysql;
+pub mod storage_postgres;
+pub mod storage_sqlite;
+pub mod storage_sql;
+
+use async_trait::async_trait;
+use chrono::{ NaiveDate, NaiveDateTime };
+use sqlx::migrate::{ AppliedMigration, Migrator };
+
+use crate::config::ConfigDatabase;
+use crate::database::table_asset::AssetRow;
+use crate::database::table_filing_fact::RowFilingFact;
+use crate::database::table_filing_legacy::{ FilingLegacyTable, RowFilingLegacy };
+use crate::database::table_sec_parse_quarantine::TableSecParseQuarantineRow;
+use crate::database::table_sec_submission_file_hash::TableSecSubmissionFileHashRow;
+use crate::database::table_security::SecurityRow;
+use crate::database::table_security_exchange_ticker::SecurityExchangeTickerRow;
+use crate::database::table_security_filing::RowSecurityFiling;
+use crate::database::table_security_name_history::SecurityNameHistoryRow;
+use crate::schema::{ Fact, ParseError };
+
+use self::storage_mysql::StorageMySql;
+use self::storage_postgres::StoragePostgres;
+use self::storage_sqlite::StorageSqlite;
+
+
+/**
+* Everything the `Table*` modules read and write, implemented once by `StorageSql` for every database. The backend is
+* picked from the scheme of `database.url`, see `connect`.
+*/
+#[async_trait]
+pub trait Storage: Send + Sync
+{
+	async fn close(&self);
+
+	// Migrations
+
+	/**
+	* Embedded migrations written for this backend
+	*/
+	fn migrator(&self) -> &'static Migrator;
+
+	async fn migrations_applied(&self) -> Result<Vec<AppliedMigration>, Box<dyn std::error::Error>>;
+
+	async fn migrations_run(&self) -> Result<(), Box<dyn std::error::Error>>;
+
+	async fn migrations_undo(&self, target: i64) -> Result<(), Box<dyn std::error::Error>>;
+
+	// asset
+
+	async fn asset_create_row(
+		&self,
+		industry: &str,
+		sector: &str,
+		name: &str,
+		asset_type: &str
+	) -> Result<AssetRow, Box<dyn std::error::Error>>;
+
+	async fn asset_update_name(&self, id: i32, name: &str) -> Result<(), Box<dyn std::error::Error>>;
+
+	// filing_assets, filing_common_stock_shares_outstanding, filing_entity_common_stock_shares_outstanding
+
+	async fn filing_legacy_create_row(&self, table: FilingLegacyTable, fact: &Fact) -> Result<(), Box<dyn std::error::Error>>;
+
+	async fn filing_legacy_read_row(
+		&self,
+		table: FilingLegacyTable,
+		fact: &Fact,
+	) -> Result<Option<RowFilingLegacy>, Box<dyn std::error::Error>>;
+
+	// filing_fact
+
+	async fn filing_fact_create_row(&self, fact: &Fact) -> Result<(), Box<dyn std::error::Error>>;
+
+	async fn filing_fact_read_row(&self, fact: &Fact) -> Result<Option<RowFilingFact>, Box<dyn std::error::Error>>;
+
+	// sec_parse_quarantine
+
+	/**
+	* Insert the file, or count one more attempt and keep the latest error if it is already quarantined
+	*/
+	async fn sec_parse_quarantine_create_row(
+		&self,
+		archive: &str,
+		parse_error: &ParseError
+	) -> Result<(), Box<dyn std::error::Error>>;
+
+	async fn sec_parse_quarantine_delete_row(&self, archive: &str, file_name: &str) -> Result<u64, Box<dyn std::error::Error>>;
+
+	async fn sec_parse_quarantine_read_rows(&self) -> Result<Vec<TableSecParseQuarantineRow>, Box<dyn std::error::Error>>;
+
+	// sec_submission_file_hash
+
+	/**
+	* Insert the fingerprint of a file, or replace the one stored for it
+	*/
+	async fn sec_submission_file_hash_create_row(
+		&self,
+		archive: &str,
+		submission_file_name: &str,
+		crc32: u32,
+		uncompressed_size: u64,
+		hash: Option<&str>,
+	) -> Result<(), Box<dyn std::error::Error>>;
+
+	async fn sec_submission_file_hash_read_row(
+		&self,
+		archive: &str,
+		submission_file_name: &str,
+		crc32: u32,
+		uncompressed_size: u64,
+		hash: Option<&str>,
+	) -> Result<Option<TableSecSubmissionFileHashRow>, Box<dyn std::error::Error>>;
+
+	// security
+
+	async fn security_get_by_cik(&self, cik: &str) -> Result<Option<SecurityRow>, Box<dyn std::error::Error>>;
+
+	#[allow(clippy::too_many_arguments)]
+	async fn security_create_row(
+		&self,
+		asset_id: i32,
+		business_city: &str,
+		business_country: &str,
+		business_state: &str,
+		business_street1: &str,
+		business_zip: &str,
+		mailing_city: &str,
+		mailing_country: &str,
+		mailing_state: &str,
+		mailing_street1: &str,
+		mailing_zip: &str,
+		category: &str,
+		cik: &str,
+		description: &str,
+		ein: &str,
+		entity_type: &str,
+		fiscal_year_end: &str,
+		flags: &str,
+		insider_transaction_for_issuer_exists: bool,
+		insider_transaction_for_owner_exists: bool,
+		investor_website: &str,
+		owner_org: &str,
+		phone: &str,
+		sic: &str,
+		sic_description: &str,
+		state_of_incorporation: &str,
+		website: &str,
+	) -> Result<SecurityRow, Box<dyn std::error::Error>>;
+
+	#[allow(clippy::too_many_arguments)]
+	async fn security_update_row(
+		&self,
+		cik: &str,
+		business_city: &str,
+		business_country: &str,
+		business_state: &str,
+		business_street1: &str,
+		business_zip: &str,
+		mailing_city: &str,
+		mailing_country: &str,
+		mailing_state: &str,
+		mailing_street1: &str,
+		mailing_zip: &str,
+		category: &str,
+		description: &str,
+		ein: &str,
+		entity_type: &str,
+		fiscal_year_end: &str,
+		flags: &str,
+		insider_transaction_for_issuer_exists: bool,
+		insider_transaction_for_owner_exists: bool,
+		investor_website: &str,
+		owner_org: &str,
+		phone: &str,
+		sic: &str,
+		sic_description: &str,
+		state_of_incorporation: &str,
+		website: &str,
+	) -> Result<SecurityRow, Box<dyn std::error::Error>>;
+
+	// security_exchange_ticker
+
+	/**
+	* Open periods of a security
+	*/
+	async fn security_exchange_ticker_read_rows(
+		&self,
+		security_cik: &str
+	) -> Result<Vec<SecurityExchangeTickerRow>, Box<dyn std::error::Error>>;
+
+	async fn security_exchange_ticker_create_row(
+		&self,
+		security_cik: &str,
+		exchange: Option<&str>,
+		ticker: &str,
+		valid_from: &NaiveDate,
+	) -> Result<(), Box<dyn std::error::Error>>;
+
+	async fn security_exchange_ticker_close_row(
+		&self,
+		security_exchange_ticker_id: i64,
+		valid_to: &NaiveDate,
+	) -> Result<(), Box<dyn std::error::Error>>;
+
+	async fn security_exchange_ticker_find_rows_by_ticker_on_date(
+		&self,
+		ticker: &str,
+		date: &NaiveDate,
+	) -> Result<Vec<SecurityExchangeTickerRow>, Box<dyn std::error::Error>>;
+
+	// security_filing
+
+	#[allow(clippy::too_many_arguments)]
+	async fn security_filing_create_row(
+		&self,
+		security_cik: &str,
+		accession_number: &str,
+		form: &str,
+		filing_date: &NaiveDate,
+		report_date: &Option<NaiveDate>,
+		acceptance: &NaiveDateTime,
+		act: &str,
+		file_number: &str,
+		film_number: &str,
+		items: &str,
+		size: Option<i64>,
+		is_xbrl: bool,
+		is_inline_xbrl: bool,
+		primary_document: &str,
+		primary_doc_description: &str,
+	) -> Result<(), Box<dyn std::error::Error>>;
+
+	#[allow(clippy::too_many_arguments)]
+	async fn security_filing_update_row(
+		&self,
+		accession_number: &str,
+		act: &str,
+		file_number: &str,
+		film_number: &str,
+		items: &str,
+		size: Option<i64>,
+		is_xbrl: bool,
+		is_inline_xbrl: bool,
+		primary_document: &str,
+		primary_doc_description: &str,
+	) -> Result<(), Box<dyn std::error::Error>>;
+
+	async fn security_filing_read_row(
+		&self,
+		accession_number: &str,
+	) -> Result<Option<RowSecurityFiling>, Box<dyn std::error::Error>>;
+
+	// security_name_history
+
+	async fn security_name_history_read_rows(
+		&self,
+		security_cik: &str
+	) -> Result<Vec<SecurityNameHistoryRow>, Box<dyn std::error::Error>>;
+
+	async fn security_name_history_create_row(
+		&self,
+		security_cik: &str,
+		name: &str,
+		from_date: Option<NaiveDate>,
+		to_date: Option<NaiveDate>,
+	) -> Result<(), Box<dyn std::error::Error>>;
+
+	async fn security_name_history_delete_row(&self, security_name_history_id: i64) -> Result<(), Box<dyn std::error::Error>>;
+}
+
+
+/**
+* Open the storage backend matching the scheme of `database.url`: mysql:// (or mariadb://), postgres:// (or
+* postgresql://) and sqlite:// (or sqlite::memory: for a throwaway in-memory database)
+* @param config_database {&ConfigDatabase} Database section of the configuration
+*/
+pub async fn connect(config_database: &ConfigDatabase) -> Result<Box<dyn Storage>, Box<dyn std::error::Error>>
+{
+	let database_url = config_database.url.as_deref().ok_or(
+		"database.url is not configured (set it in the config file or APP__DATABASE__URL)"
+	)?;
+
+	let scheme: &str = database_url.split_once(':').map(|(scheme, _)| scheme).unwrap_or_default();
+
+	match scheme
+	{
+		"mysql" | "mariadb" => Ok(Box::new(StorageMySql::connect(config_database, database_url).await?)),
+		"postgres" | "postgresql" => Ok(Box::new(StoragePostgres::connect(config_database, database_url).await?)),
+		"sqlite" => Ok(Box::new(StorageSqlite::connect(config_database, database_url).await?)),
+		_ => Err(format!("database.url has an unsupported scheme \"{}\", use mysql://, postgres:// or sqlite://", scheme).into()),
+	}
+}
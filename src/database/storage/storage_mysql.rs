@@ -0,0 +1,78 @@
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+use sqlx::{ MySql, Pool };
+use sqlx::migrate::Migrator;
+use sqlx::mysql::{ MySqlPoolOptions, MySqlQueryResult };
+use std::time::Duration;
+
+use super::storage_sql::{ Dialect, SqlQuery, StorageSql };
+use crate::config::ConfigDatabase;
+use crate::{ log_info };
+
+
+static MIGRATOR: Migrator = sqlx::migrate!("./migrations/mysql");
+
+
+/**
+* MySQL / MariaDB backend
+*/
+pub type StorageMySql = StorageSql<MySql>;
+
+
+impl StorageSql<MySql>
+{
+	pub async fn connect(config_database: &ConfigDatabase, database_url: &str) -> Result<Self, Box<dyn std::error::Error>>
+	{
+		log_info!("Creating connection to MySQL Database..");
+
+		let pool = MySqlPoolOptions::new().max_connections(
+			config_database.max_connections
+		).min_connections(
+			config_database.min_connections
+		).acquire_timeout(
+			Duration::from_secs(config_database.acquire_timeout_seconds)
+		).connect(
+			database_url
+		).await?;
+
+		Ok(Self { pool })
+	}
+}
+
+
+#[async_trait]
+impl Dialect for MySql
+{
+	fn migrator() -> &'static Migrator
+	{
+		&MIGRATOR
+	}
+
+	fn quote(identifier: &str) -> String
+	{
+		format!("`{}`", identifier)
+	}
+
+	fn upsert(_conflict_columns: &[&str], update_columns: &[&str]) -> String
+	{
+		// MySQL finds the conflicting row through any unique key on its own
+		let assignments: Vec<String> = update_columns.iter().map(|c| format!("{} = VALUES({})", c, c)).collect();
+
+		format!("ON DUPLICATE KEY UPDATE {}", assignments.join(", "))
+	}
+
+	fn bind_decimal(query: SqlQuery<'_, Self>, val: Decimal) -> SqlQuery<'_, Self>
+	{
+		query.bind(val)
+	}
+
+	async fn execute_returning_id(pool: &Pool<Self>, query: SqlQuery<'_, Self>) -> Result<i64, sqlx::Error>
+	{
+		Ok(query.execute(pool).await?.last_insert_id() as i64)
+	}
+
+	fn rows_affected(result: &MySqlQueryResult) -> u64
+	{
+		result.rows_affected()
+	}
+}
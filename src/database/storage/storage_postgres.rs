@@ -0,0 +1,110 @@
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+use sqlx::{ Pool, Postgres, Row };
+use sqlx::migrate::Migrator;
+use sqlx::postgres::{ PgPoolOptions, PgQueryResult };
+use std::time::Duration;
+
+use super::storage_sql::{ Dialect, SqlQuery, StorageSql };
+use crate::config::ConfigDatabase;
+use crate::{ log_info };
+
+
+static MIGRATOR: Migrator = sqlx::migrate!("./migrations/postgres");
+
+
+/**
+* PostgreSQL backend
+*/
+pub type StoragePostgres = StorageSql<Postgres>;
+
+
+impl StorageSql<Postgres>
+{
+	pub async fn connect(config_database: &ConfigDatabase, database_url: &str) -> Result<Self, Box<dyn std::error::Error>>
+	{
+		log_info!("Creating connection to PostgreSQL Database..");
+
+		let pool = PgPoolOptions::new().max_connections(
+			config_database.max_connections
+		).min_connections(
+			config_database.min_connections
+		).acquire_timeout(
+			Duration::from_secs(config_database.acquire_timeout_seconds)
+		).connect(
+			database_url
+		).await?;
+
+		Ok(Self { pool })
+	}
+}
+
+
+#[async_trait]
+impl Dialect for Postgres
+{
+	fn migrator() -> &'static Migrator
+	{
+		&MIGRATOR
+	}
+
+	/**
+	* Number the placeholders $1, $2, .. in order. None of the queries has a literal question mark.
+	*/
+	fn sql(sql: String) -> String
+	{
+		let mut numbered = String::with_capacity(sql.len() + 16);
+		let mut n: usize = 0;
+
+		for c in sql.chars()
+		{
+			if c == '?'
+			{
+				n += 1;
+
+				numbered.push_str(&format!("${}", n));
+			}
+			else
+			{
+				numbered.push(c);
+			}
+		}
+
+		numbered
+	}
+
+	fn quote(identifier: &str) -> String
+	{
+		format!("\"{}\"", identifier)
+	}
+
+	fn upsert(conflict_columns: &[&str], update_columns: &[&str]) -> String
+	{
+		let assignments: Vec<String> = update_columns.iter().map(|c| format!("{} = EXCLUDED.{}", c, c)).collect();
+
+		format!("ON CONFLICT ({}) DO UPDATE SET {}", conflict_columns.join(", "), assignments.join(", "))
+	}
+
+	fn bind_decimal(query: SqlQuery<'_, Self>, val: Decimal) -> SqlQuery<'_, Self>
+	{
+		query.bind(val)
+	}
+
+	// Postgres has no last_insert_id, the generated ID comes back through RETURNING
+	fn returning_id() -> &'static str
+	{
+		" RETURNING id"
+	}
+
+	async fn execute_returning_id(pool: &Pool<Self>, query: SqlQuery<'_, Self>) -> Result<i64, sqlx::Error>
+	{
+		let id: i32 = query.fetch_one(pool).await?.try_get(0)?;
+
+		Ok(i64::from(id))
+	}
+
+	fn rows_affected(result: &PgQueryResult) -> u64
+	{
+		result.rows_affected()
+	}
+}
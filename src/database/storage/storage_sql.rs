@@ -0,0 +1,1146 @@
+use async_trait::async_trait;
+use chrono::{ NaiveDate, NaiveDateTime };
+use rust_decimal::Decimal;
+use sqlx::{ Database, Encode, Executor, FromRow, IntoArguments, Pool, Type };
+use sqlx::database::HasArguments;
+use sqlx::migrate::{ AppliedMigration, Migrate, Migrator };
+use sqlx::query::Query;
+
+use super::Storage;
+use crate::database::table_asset::AssetRow;
+use crate::database::table_filing_fact::RowFilingFact;
+use crate::database::table_filing_legacy::{ FilingLegacyTable, RowFilingLegacy };
+use crate::database::table_sec_parse_quarantine::TableSecParseQuarantineRow;
+use crate::database::table_sec_submission_file_hash::TableSecSubmissionFileHashRow;
+use crate::database::table_security::SecurityRow;
+use crate::database::table_security_exchange_ticker::SecurityExchangeTickerRow;
+use crate::database::table_security_filing::RowSecurityFiling;
+use crate::database::table_security_name_history::SecurityNameHistoryRow;
+use crate::schema::{ Fact, ParseError };
+
+
+pub type SqlQuery<'q, DB> = Query<'q, DB, <DB as HasArguments<'q>>::Arguments>;
+
+
+/**
+* What the SQL of the supported databases disagrees on. Queries are written once in `StorageSql`, with `?`
+* placeholders, and each backend only describes its differences here.
+*/
+#[async_trait]
+pub trait Dialect: Database
+{
+	/**
+	* Embedded migrations written for this backend
+	*/
+	fn migrator() -> &'static Migrator;
+
+	/**
+	* Rewrite the `?` placeholders of a query into the ones this database expects
+	*/
+	fn sql(sql: String) -> String
+	{
+		sql
+	}
+
+	/**
+	* Quote an identifier that is also a keyword, e.g. `end`
+	*/
+	fn quote(identifier: &str) -> String;
+
+	/**
+	* Clause that turns an INSERT into an upsert on `conflict_columns`, overwriting `update_columns` with the values
+	* that were about to be inserted. More assignments can follow it, separated by a comma.
+	*/
+	fn upsert(conflict_columns: &[&str], update_columns: &[&str]) -> String;
+
+	/**
+	* Bind an XBRL value
+	*/
+	fn bind_decimal(query: SqlQuery<'_, Self>, val: Decimal) -> SqlQuery<'_, Self>;
+
+	/**
+	* Appended to an INSERT so `execute_returning_id` can read the generated id, where the database needs it
+	*/
+	fn returning_id() -> &'static str
+	{
+		""
+	}
+
+	/**
+	* Run an INSERT and return the id it generated
+	*/
+	async fn execute_returning_id(pool: &Pool<Self>, query: SqlQuery<'_, Self>) -> Result<i64, sqlx::Error>;
+
+	fn rows_affected(result: &Self::QueryResult) -> u64;
+}
+
+
+/**
+* Parse a YYYY-MM-DD date of a companyfacts fact so it binds as a date on every backend
+* @param raw {&str} Date as SEC sends it
+*/
+fn parse_date(raw: &str) -> Result<NaiveDate, Box<dyn std::error::Error>>
+{
+	NaiveDate::parse_from_str(raw, "%Y-%m-%d").map_err(|e| format!("Invalid date {:?}: {}", raw, e).into())
+}
+
+
+/**
+* Storage on any database with a `Dialect`. Every query lives here, the backend modules only connect and describe
+* their dialect.
+*/
+pub struct StorageSql<DB: Database>
+{
+	pub(super) pool: Pool<DB>,
+}
+
+
+#[async_trait]
+impl<DB: Dialect> Storage for StorageSql<DB>
+where
+	DB::Connection: Migrate,
+	for<'c> &'c mut DB::Connection: Executor<'c, Database = DB>,
+	for<'q> <DB as HasArguments<'q>>::Arguments: IntoArguments<'q, DB>,
+	for<'q> &'q str: Encode<'q, DB> + Type<DB>,
+	for<'q> bool: Encode<'q, DB> + Type<DB>,
+	for<'q> i32: Encode<'q, DB> + Type<DB>,
+	for<'q> i64: Encode<'q, DB> + Type<DB>,
+	for<'q> NaiveDate: Encode<'q, DB> + Type<DB>,
+	for<'q> NaiveDateTime: Encode<'q, DB> + Type<DB>,
+	for<'q> Option<&'q str>: Encode<'q, DB> + Type<DB>,
+	for<'q> Option<i64>: Encode<'q, DB> + Type<DB>,
+	for<'q> Option<NaiveDate>: Encode<'q, DB> + Type<DB>,
+	for<'r> AssetRow: FromRow<'r, DB::Row>,
+	for<'r> RowFilingFact: FromRow<'r, DB::Row>,
+	for<'r> RowFilingLegacy: FromRow<'r, DB::Row>,
+	for<'r> TableSecParseQuarantineRow: FromRow<'r, DB::Row>,
+	for<'r> TableSecSubmissionFileHashRow: FromRow<'r, DB::Row>,
+	for<'r> SecurityRow: FromRow<'r, DB::Row>,
+	for<'r> SecurityExchangeTickerRow: FromRow<'r, DB::Row>,
+	for<'r> RowSecurityFiling: FromRow<'r, DB::Row>,
+	for<'r> SecurityNameHistoryRow: FromRow<'r, DB::Row>,
+{
+	async fn close(&self)
+	{
+		self.pool.close().await;
+	}
+
+	fn migrator(&self) -> &'static Migrator
+	{
+		DB::migrator()
+	}
+
+	async fn migrations_applied(&self) -> Result<Vec<AppliedMigration>, Box<dyn std::error::Error>>
+	{
+		let mut conn = self.pool.acquire().await?;
+
+		conn.ensure_migrations_table().await?;
+
+		if let Some(version) = conn.dirty_version().await?
+		{
+			return Err(format!("Migration {} was left partially applied, fix the database by hand", version).into());
+		}
+
+		Ok(conn.list_applied_migrations().await?)
+	}
+
+	async fn migrations_run(&self) -> Result<(), Box<dyn std::error::Error>>
+	{
+		DB::migrator().run(&self.pool).await?;
+
+		Ok(())
+	}
+
+	async fn migrations_undo(&self, target: i64) -> Result<(), Box<dyn std::error::Error>>
+	{
+		DB::migrator().undo(&self.pool, target).await?;
+
+		Ok(())
+	}
+
+	async fn asset_create_row(
+		&self,
+		industry: &str,
+		sector: &str,
+		name: &str,
+		asset_type: &str
+	) -> Result<AssetRow, Box<dyn std::error::Error>>
+	{
+		let sql: String = DB::sql(
+			format!(
+				"INSERT INTO asset (industry, sector, name, {}) VALUES (?, ?, ?, ?){}",
+				DB::quote("type"),
+				DB::returning_id()
+			)
+		);
+
+		let id: i64 = DB::execute_returning_id(
+			&self.pool,
+			sqlx::query::<DB>(&sql).bind(industry).bind(sector).bind(name).bind(asset_type)
+		).await?;
+
+		// Fetch and return the inserted row
+		let asset = sqlx::query_as::<_, AssetRow>(
+			&DB::sql(String::from("SELECT * FROM asset WHERE id = ?"))
+		).bind(
+			id
+		).fetch_one(
+			&self.pool
+		).await?;
+
+		Ok(asset)
+	}
+
+	async fn asset_update_name(&self, id: i32, name: &str) -> Result<(), Box<dyn std::error::Error>>
+	{
+		sqlx::query::<DB>(&DB::sql(String::from("UPDATE asset SET name = ? WHERE id = ?"))).bind(
+			name
+		).bind(
+			id
+		).execute(
+			&self.pool
+		).await?;
+
+		Ok(())
+	}
+
+	async fn filing_legacy_create_row(&self, table: FilingLegacyTable, fact: &Fact) -> Result<(), Box<dyn std::error::Error>>
+	{
+		let start: Option<NaiveDate> = fact.start.as_deref().map(parse_date).transpose()?;
+		let end: NaiveDate = parse_date(&fact.end)?;
+		let filed: Option<NaiveDate> = fact.filed.as_deref().map(parse_date).transpose()?;
+
+		let sql: String = DB::sql(
+			format!(
+				r#"
+					INSERT INTO {} (
+						security_filing_accession_number,
+						unit,
+						start,
+						{},
+						fp,
+						fy,
+						val,
+						filed,
+						form,
+						frame
+					)
+					VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+				"#,
+				table.name(),
+				DB::quote("end")
+			)
+		);
+
+		let query = sqlx::query::<DB>(&sql).bind(
+			fact.security_filing_accession_number.as_str()
+		).bind(
+			fact.unit.as_str()
+		).bind(
+			start
+		).bind(
+			end
+		).bind(
+			fact.fp.as_str()
+		).bind(
+			fact.fy
+		);
+
+		DB::bind_decimal(query, fact.val).bind(
+			filed
+		).bind(
+			fact.form.as_deref().unwrap_or_default()
+		).bind(
+			fact.frame.as_deref()
+		).execute(
+			&self.pool
+		).await?;
+
+		Ok(())
+	}
+
+	async fn filing_legacy_read_row(
+		&self,
+		table: FilingLegacyTable,
+		fact: &Fact,
+	) -> Result<Option<RowFilingLegacy>, Box<dyn std::error::Error>>
+	{
+		if !table.keyed_by_end()
+		{
+			let sql: String = DB::sql(format!("SELECT * FROM {} WHERE security_filing_accession_number = ?", table.name()));
+
+			let existing_row = sqlx::query_as::<_, RowFilingLegacy>(&sql).bind(
+				fact.security_filing_accession_number.as_str()
+			).fetch_optional(
+				&self.pool
+			).await?;
+
+			return Ok(existing_row);
+		}
+
+		let end: NaiveDate = parse_date(&fact.end)?;
+
+		let sql: String = DB::sql(
+			format!(
+				"SELECT * FROM {} WHERE security_filing_accession_number = ? AND {} = ?",
+				table.name(),
+				DB::quote("end")
+			)
+		);
+
+		let existing_row = sqlx::query_as::<_, RowFilingLegacy>(&sql).bind(
+			fact.security_filing_accession_number.as_str()
+		).bind(
+			end
+		).fetch_optional(
+			&self.pool
+		).await?;
+
+		Ok(existing_row)
+	}
+
+	async fn filing_fact_create_row(&self, fact: &Fact) -> Result<(), Box<dyn std::error::Error>>
+	{
+		let start: Option<NaiveDate> = fact.start.as_deref().map(parse_date).transpose()?;
+		let end: NaiveDate = parse_date(&fact.end)?;
+		let filed: NaiveDate = parse_date(fact.filed.as_deref().ok_or("A filing_fact row needs the filed date")?)?;
+		let form: &str = fact.form.as_deref().ok_or("A filing_fact row needs the form")?;
+
+		let sql: String = DB::sql(
+			format!(
+				r#"
+					INSERT INTO filing_fact (
+						security_filing_accession_number,
+						canonical_concept,
+						taxonomy,
+						concept,
+						unit,
+						start,
+						{},
+						fp,
+						fy,
+						val,
+						filed,
+						form,
+						frame
+					)
+					VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+				"#,
+				DB::quote("end")
+			)
+		);
+
+		let query = sqlx::query::<DB>(&sql).bind(
+			fact.security_filing_accession_number.as_str()
+		).bind(
+			fact.canonical_concept.as_str()
+		).bind(
+			fact.taxonomy.as_str()
+		).bind(
+			fact.concept.as_str()
+		).bind(
+			fact.unit.as_str()
+		).bind(
+			start
+		).bind(
+			end
+		).bind(
+			fact.fp.as_str()
+		).bind(
+			fact.fy
+		);
+
+		DB::bind_decimal(query, fact.val).bind(
+			filed
+		).bind(
+			form
+		).bind(
+			fact.frame.as_deref()
+		).execute(
+			&self.pool
+		).await?;
+
+		Ok(())
+	}
+
+	async fn filing_fact_read_row(&self, fact: &Fact) -> Result<Option<RowFilingFact>, Box<dyn std::error::Error>>
+	{
+		let start: Option<NaiveDate> = fact.start.as_deref().map(parse_date).transpose()?;
+		let end: NaiveDate = parse_date(&fact.end)?;
+
+		// start is NULL for instant facts, and NULL never equals a placeholder
+		let sql: String = DB::sql(
+			format!(
+				r#"
+					SELECT * FROM filing_fact
+					WHERE security_filing_accession_number = ?
+						AND taxonomy = ?
+						AND concept = ?
+						AND unit = ?
+						AND {}
+						AND {} = ?
+				"#,
+				if start.is_some() { "start = ?" } else { "start IS NULL" },
+				DB::quote("end")
+			)
+		);
+
+		let mut query = sqlx::query_as::<_, RowFilingFact>(&sql).bind(
+			fact.security_filing_accession_number.as_str()
+		).bind(
+			fact.taxonomy.as_str()
+		).bind(
+			fact.concept.as_str()
+		).bind(
+			fact.unit.as_str()
+		);
+
+		if let Some(start) = start
+		{
+			query = query.bind(start);
+		}
+
+		let existing_row = query.bind(end).fetch_optional(&self.pool).await?;
+
+		Ok(existing_row)
+	}
+
+	async fn sec_parse_quarantine_create_row(
+		&self,
+		archive: &str,
+		parse_error: &ParseError
+	) -> Result<(), Box<dyn std::error::Error>>
+	{
+		let sql: String = DB::sql(
+			format!(
+				r#"
+					INSERT INTO sec_parse_quarantine (archive, file_name, field, item_index, raw_value, message, attempts)
+					VALUES (?, ?, ?, ?, ?, ?, 1)
+					{},
+						attempts = sec_parse_quarantine.attempts + 1,
+						last_seen_at = CURRENT_TIMESTAMP
+				"#,
+				DB::upsert(&["archive", "file_name"], &["field", "item_index", "raw_value", "message"])
+			)
+		);
+
+		sqlx::query::<DB>(&sql).bind(
+			archive
+		).bind(
+			parse_error.file_name.as_str()
+		).bind(
+			parse_error.field.as_str()
+		).bind(
+			parse_error.index.map(|i| i as i64)
+		).bind(
+			parse_error.raw_value.as_deref()
+		).bind(
+			parse_error.message.as_str()
+		).execute(
+			&self.pool
+		).await?;
+
+		Ok(())
+	}
+
+	async fn sec_parse_quarantine_delete_row(&self, archive: &str, file_name: &str) -> Result<u64, Box<dyn std::error::Error>>
+	{
+		let sql: String = DB::sql(String::from("DELETE FROM sec_parse_quarantine WHERE archive = ? AND file_name = ?"));
+
+		let result = sqlx::query::<DB>(&sql).bind(archive).bind(file_name).execute(&self.pool).await?;
+
+		Ok(DB::rows_affected(&result))
+	}
+
+	async fn sec_parse_quarantine_read_rows(&self) -> Result<Vec<TableSecParseQuarantineRow>, Box<dyn std::error::Error>>
+	{
+		let rows = sqlx::query_as::<_, TableSecParseQuarantineRow>(
+			r#"
+				SELECT archive, file_name, field, item_index, raw_value, message, attempts
+				FROM sec_parse_quarantine
+				ORDER BY archive, file_name
+			"#
+		).fetch_all(
+			&self.pool
+		).await?;
+
+		Ok(rows)
+	}
+
+	async fn sec_submission_file_hash_create_row(
+		&self,
+		archive: &str,
+		submission_file_name: &str,
+		crc32: u32,
+		uncompressed_size: u64,
+		hash: Option<&str>,
+	) -> Result<(), Box<dyn std::error::Error>>
+	{
+		let sql: String = DB::sql(
+			format!(
+				r#"
+					INSERT INTO sec_submission_file_hash (archive, submission_file_name, crc32, uncompressed_size, hash)
+					VALUES (?, ?, ?, ?, ?)
+					{}
+				"#,
+				DB::upsert(&["archive", "submission_file_name"], &["crc32", "uncompressed_size", "hash"])
+			)
+		);
+
+		// PostgreSQL has no unsigned integers, so both are bound as BIGINT everywhere
+		sqlx::query::<DB>(&sql).bind(
+			archive
+		).bind(
+			submission_file_name
+		).bind(
+			i64::from(crc32)
+		).bind(
+			uncompressed_size as i64
+		).bind(
+			hash
+		).execute(
+			&self.pool
+		).await?;
+
+		Ok(())
+	}
+
+	async fn sec_submission_file_hash_read_row(
+		&self,
+		archive: &str,
+		submission_file_name: &str,
+		crc32: u32,
+		uncompressed_size: u64,
+		hash: Option<&str>,
+	) -> Result<Option<TableSecSubmissionFileHashRow>, Box<dyn std::error::Error>>
+	{
+		// Without a SHA-256 the CRC32 and size decide alone
+		let sql: String = DB::sql(
+			format!(
+				r#"
+					SELECT * FROM sec_submission_file_hash
+					WHERE archive = ? AND submission_file_name = ? AND crc32 = ? AND uncompressed_size = ?{}
+				"#,
+				if hash.is_some() { " AND hash = ?" } else { "" }
+			)
+		);
+
+		let mut query = sqlx::query_as::<_, TableSecSubmissionFileHashRow>(&sql).bind(
+			archive
+		).bind(
+			submission_file_name
+		).bind(
+			i64::from(crc32)
+		).bind(
+			uncompressed_size as i64
+		);
+
+		if let Some(hash) = hash
+		{
+			query = query.bind(hash);
+		}
+
+		Ok(query.fetch_optional(&self.pool).await?)
+	}
+
+	async fn security_get_by_cik(&self, cik: &str) -> Result<Option<SecurityRow>, Box<dyn std::error::Error>>
+	{
+		let row = sqlx::query_as::<_, SecurityRow>(
+			&DB::sql(String::from("SELECT asset_id FROM security WHERE cik = ?"))
+		).bind(
+			cik
+		).fetch_optional(
+			&self.pool
+		).await?;
+
+		Ok(row)
+	}
+
+	async fn security_create_row(
+		&self,
+		asset_id: i32,
+		business_city: &str,
+		business_country: &str,
+		business_state: &str,
+		business_street1: &str,
+		business_zip: &str,
+		mailing_city: &str,
+		mailing_country: &str,
+		mailing_state: &str,
+		mailing_street1: &str,
+		mailing_zip: &str,
+		category: &str,
+		cik: &str,
+		description: &str,
+		ein: &str,
+		entity_type: &str,
+		fiscal_year_end: &str,
+		flags: &str,
+		insider_transaction_for_issuer_exists: bool,
+		insider_transaction_for_owner_exists: bool,
+		investor_website: &str,
+		owner_org: &str,
+		phone: &str,
+		sic: &str,
+		sic_description: &str,
+		state_of_incorporation: &str,
+		website: &str,
+	) -> Result<SecurityRow, Box<dyn std::error::Error>>
+	{
+		let sql: String = DB::sql(
+			String::from(
+				r#"
+					INSERT INTO security (
+						asset_id,
+						business_street1,
+						business_city,
+						business_country,
+						business_state,
+						business_zip,
+						mailing_street1,
+						mailing_city,
+						mailing_country,
+						mailing_state,
+						mailing_zip,
+						category,
+						cik,
+						description,
+						ein,
+						entity_type,
+						fiscal_year_end,
+						flags,
+						insider_transaction_for_issuer_exists,
+						insider_transaction_for_owner_exists,
+						investor_website,
+						owner_org,
+						phone,
+						sic,
+						sic_description,
+						state_of_incorporation,
+						website
+					) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+				"#
+			)
+		);
+
+		sqlx::query::<DB>(&sql).bind(
+			asset_id
+		).bind(
+			business_street1
+		).bind(
+			business_city
+		).bind(
+			business_country
+		).bind(
+			business_state
+		).bind(
+			business_zip
+		).bind(
+			mailing_street1
+		).bind(
+			mailing_city
+		).bind(
+			mailing_country
+		).bind(
+			mailing_state
+		).bind(
+			mailing_zip
+		).bind(
+			category
+		).bind(
+			cik
+		).bind(
+			description
+		).bind(
+			ein
+		).bind(
+			entity_type
+		).bind(
+			fiscal_year_end
+		).bind(
+			flags
+		).bind(
+			insider_transaction_for_issuer_exists
+		).bind(
+			insider_transaction_for_owner_exists
+		).bind(
+			investor_website
+		).bind(
+			owner_org
+		).bind(
+			phone
+		).bind(
+			sic
+		).bind(
+			sic_description
+		).bind(
+			state_of_incorporation
+		).bind(
+			website
+		).execute(
+			&self.pool
+		).await?;
+
+		// Fetch and return the inserted row
+		let security = sqlx::query_as::<_, SecurityRow>(
+			&DB::sql(String::from("SELECT * FROM security WHERE cik = ?"))
+		).bind(
+			cik
+		).fetch_one(
+			&self.pool
+		).await?;
+
+		Ok(security)
+	}
+
+	async fn security_update_row(
+		&self,
+		cik: &str,
+		business_city: &str,
+		business_country: &str,
+		business_state: &str,
+		business_street1: &str,
+		business_zip: &str,
+		mailing_city: &str,
+		mailing_country: &str,
+		mailing_state: &str,
+		mailing_street1: &str,
+		mailing_zip: &str,
+		category: &str,
+		description: &str,
+		ein: &str,
+		entity_type: &str,
+		fiscal_year_end: &str,
+		flags: &str,
+		insider_transaction_for_issuer_exists: bool,
+		insider_transaction_for_owner_exists: bool,
+		investor_website: &str,
+		owner_org: &str,
+		phone: &str,
+		sic: &str,
+		sic_description: &str,
+		state_of_incorporation: &str,
+		website: &str,
+	) -> Result<SecurityRow, Box<dyn std::error::Error>>
+	{
+		let sql: String = DB::sql(
+			String::from(
+				r#"
+					UPDATE security
+					SET
+						business_city = ?,
+						business_country = ?,
+						business_state = ?,
+						business_street1 = ?,
+						business_zip = ?,
+						mailing_city = ?,
+						mailing_country = ?,
+						mailing_state = ?,
+						mailing_street1 = ?,
+						mailing_zip = ?,
+						category = ?,
+						description = ?,
+						ein = ?,
+						entity_type = ?,
+						fiscal_year_end = ?,
+						flags = ?,
+						insider_transaction_for_issuer_exists = ?,
+						insider_transaction_for_owner_exists = ?,
+						investor_website = ?,
+						owner_org = ?,
+						phone = ?,
+						sic = ?,
+						sic_description = ?,
+						state_of_incorporation = ?,
+						website = ?
+					WHERE
+						cik = ?
+				"#
+			)
+		);
+
+		sqlx::query::<DB>(&sql).bind(
+			business_city
+		).bind(
+			business_country
+		).bind(
+			business_state
+		).bind(
+			business_street1
+		).bind(
+			business_zip
+		).bind(
+			mailing_city
+		).bind(
+			mailing_country
+		).bind(
+			mailing_state
+		).bind(
+			mailing_street1
+		).bind(
+			mailing_zip
+		).bind(
+			category
+		).bind(
+			description
+		).bind(
+			ein
+		).bind(
+			entity_type
+		).bind(
+			fiscal_year_end
+		).bind(
+			flags
+		).bind(
+			insider_transaction_for_issuer_exists
+		).bind(
+			insider_transaction_for_owner_exists
+		).bind(
+			investor_website
+		).bind(
+			owner_org
+		).bind(
+			phone
+		).bind(
+			sic
+		).bind(
+			sic_description
+		).bind(
+			state_of_incorporation
+		).bind(
+			website
+		).bind(
+			cik
+		).execute(
+			&self.pool
+		).await?;
+
+		// Fetch and return the updated row
+		let security = sqlx::query_as::<_, SecurityRow>(
+			&DB::sql(String::from("SELECT * FROM security WHERE cik = ?"))
+		).bind(
+			cik
+		).fetch_one(
+			&self.pool
+		).await?;
+
+		Ok(security)
+	}
+
+	async fn security_exchange_ticker_read_rows(
+		&self,
+		security_cik: &str
+	) -> Result<Vec<SecurityExchangeTickerRow>, Box<dyn std::error::Error>>
+	{
+		let sql: String = DB::sql(
+			String::from(
+				r#"
+					SELECT id, security_cik, exchange, ticker, valid_from, valid_to
+					FROM security_exchange_ticker
+					WHERE security_cik = ? AND valid_to IS NULL
+				"#
+			)
+		);
+
+		let existing_rows = sqlx::query_as::<_, SecurityExchangeTickerRow>(&sql).bind(
+			security_cik
+		).fetch_all(
+			&self.pool
+		).await?;
+
+		Ok(existing_rows)
+	}
+
+	async fn security_exchange_ticker_create_row(
+		&self,
+		security_cik: &str,
+		exchange: Option<&str>,
+		ticker: &str,
+		valid_from: &NaiveDate,
+	) -> Result<(), Box<dyn std::error::Error>>
+	{
+		let sql: String = DB::sql(
+			String::from(
+				"INSERT INTO security_exchange_ticker (security_cik, exchange, ticker, valid_from) VALUES (?, ?, ?, ?)"
+			)
+		);
+
+		sqlx::query::<DB>(&sql).bind(
+			security_cik
+		).bind(
+			exchange
+		).bind(
+			ticker
+		).bind(
+			*valid_from
+		).execute(
+			&self.pool
+		).await?;
+
+		Ok(())
+	}
+
+	async fn security_exchange_ticker_close_row(
+		&self,
+		security_exchange_ticker_id: i64,
+		valid_to: &NaiveDate,
+	) -> Result<(), Box<dyn std::error::Error>>
+	{
+		let sql: String = DB::sql(String::from("UPDATE security_exchange_ticker SET valid_to = ? WHERE id = ?"));
+
+		sqlx::query::<DB>(&sql).bind(
+			*valid_to
+		).bind(
+			security_exchange_ticker_id
+		).execute(
+			&self.pool
+		).await?;
+
+		Ok(())
+	}
+
+	async fn security_exchange_ticker_find_rows_by_ticker_on_date(
+		&self,
+		ticker: &str,
+		date: &NaiveDate,
+	) -> Result<Vec<SecurityExchangeTickerRow>, Box<dyn std::error::Error>>
+	{
+		let sql: String = DB::sql(
+			String::from(
+				r#"
+					SELECT id, security_cik, exchange, ticker, valid_from, valid_to
+					FROM security_exchange_ticker
+					WHERE
+						ticker = ?
+						AND (valid_from IS NULL OR valid_from <= ?)
+						AND (valid_to IS NULL OR valid_to > ?)
+					ORDER BY security_cik, exchange
+				"#
+			)
+		);
+
+		let rows = sqlx::query_as::<_, SecurityExchangeTickerRow>(&sql).bind(
+			ticker
+		).bind(
+			*date
+		).bind(
+			*date
+		).fetch_all(
+			&self.pool
+		).await?;
+
+		Ok(rows)
+	}
+
+	async fn security_filing_create_row(
+		&self,
+		security_cik: &str,
+		accession_number: &str,
+		form: &str,
+		filing_date: &NaiveDate,
+		report_date: &Option<NaiveDate>,
+		acceptance: &NaiveDateTime,
+		act: &str,
+		file_number: &str,
+		film_number: &str,
+		items: &str,
+		size: Option<i64>,
+		is_xbrl: bool,
+		is_inline_xbrl: bool,
+		primary_document: &str,
+		primary_doc_description: &str,
+	) -> Result<(), Box<dyn std::error::Error>>
+	{
+		let sql: String = DB::sql(
+			String::from(
+				r#"
+					INSERT INTO security_filing (
+						security_cik,
+						accession_number,
+						form,
+						filing_date,
+						report_date,
+						acceptance,
+						act,
+						file_number,
+						film_number,
+						items,
+						size,
+						is_xbrl,
+						is_inline_xbrl,
+						primary_document,
+						primary_doc_description
+					)
+					VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+				"#
+			)
+		);
+
+		sqlx::query::<DB>(&sql).bind(
+			security_cik
+		).bind(
+			accession_number
+		).bind(
+			form
+		).bind(
+			*filing_date
+		).bind(
+			*report_date
+		).bind(
+			*acceptance
+		).bind(
+			act
+		).bind(
+			file_number
+		).bind(
+			film_number
+		).bind(
+			items
+		).bind(
+			size
+		).bind(
+			is_xbrl
+		).bind(
+			is_inline_xbrl
+		).bind(
+			primary_document
+		).bind(
+			primary_doc_description
+		).execute(
+			&self.pool
+		).await?;
+
+		Ok(())
+	}
+
+	async fn security_filing_update_row(
+		&self,
+		accession_number: &str,
+		act: &str,
+		file_number: &str,
+		film_number: &str,
+		items: &str,
+		size: Option<i64>,
+		is_xbrl: bool,
+		is_inline_xbrl: bool,
+		primary_document: &str,
+		primary_doc_description: &str,
+	) -> Result<(), Box<dyn std::error::Error>>
+	{
+		let sql: String = DB::sql(
+			String::from(
+				r#"
+					UPDATE security_filing
+					SET
+						act = ?,
+						file_number = ?,
+						film_number = ?,
+						items = ?,
+						size = ?,
+						is_xbrl = ?,
+						is_inline_xbrl = ?,
+						primary_document = ?,
+						primary_doc_description = ?
+					WHERE
+						accession_number = ?
+				"#
+			)
+		);
+
+		sqlx::query::<DB>(&sql).bind(
+			act
+		).bind(
+			file_number
+		).bind(
+			film_number
+		).bind(
+			items
+		).bind(
+			size
+		).bind(
+			is_xbrl
+		).bind(
+			is_inline_xbrl
+		).bind(
+			primary_document
+		).bind(
+			primary_doc_description
+		).bind(
+			accession_number
+		).execute(
+			&self.pool
+		).await?;
+
+		Ok(())
+	}
+
+	async fn security_filing_read_row(
+		&self,
+		accession_number: &str,
+	) -> Result<Option<RowSecurityFiling>, Box<dyn std::error::Error>>
+	{
+		let existing_row = sqlx::query_as::<_, RowSecurityFiling>(
+			&DB::sql(String::from("SELECT * FROM security_filing WHERE accession_number = ?"))
+		).bind(
+			accession_number
+		).fetch_optional(
+			&self.pool
+		).await?;
+
+		Ok(existing_row)
+	}
+
+	async fn security_name_history_read_rows(
+		&self,
+		security_cik: &str
+	) -> Result<Vec<SecurityNameHistoryRow>, Box<dyn std::error::Error>>
+	{
+		let existing_rows = sqlx::query_as::<_, SecurityNameHistoryRow>(
+			&DB::sql(String::from("SELECT id, name, from_date, to_date FROM security_name_history WHERE security_cik = ?"))
+		).bind(
+			security_cik
+		).fetch_all(
+			&self.pool
+		).await?;
+
+		Ok(existing_rows)
+	}
+
+	async fn security_name_history_create_row(
+		&self,
+		security_cik: &str,
+		name: &str,
+		from_date: Option<NaiveDate>,
+		to_date: Option<NaiveDate>,
+	) -> Result<(), Box<dyn std::error::Error>>
+	{
+		let sql: String = DB::sql(
+			String::from("INSERT INTO security_name_history (security_cik, name, from_date, to_date) VALUES (?, ?, ?, ?)")
+		);
+
+		sqlx::query::<DB>(&sql).bind(
+			security_cik
+		).bind(
+			name
+		).bind(
+			from_date
+		).bind(
+			to_date
+		).execute(
+			&self.pool
+		).await?;
+
+		Ok(())
+	}
+
+	async fn security_name_history_delete_row(&self, security_name_history_id: i64) -> Result<(), Box<dyn std::error::Error>>
+	{
+		sqlx::query::<DB>(&DB::sql(String::from("DELETE FROM security_name_history WHERE id = ?"))).bind(
+			security_name_history_id
+		).execute(
+			&self.pool
+		).await?;
+
+		Ok(())
+	}
+}
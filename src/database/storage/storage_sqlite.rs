@@ -0,0 +1,198 @@
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+use sqlx::{ Pool, Sqlite };
+use sqlx::migrate::Migrator;
+use sqlx::sqlite::{ SqliteConnectOptions, SqlitePoolOptions, SqliteQueryResult };
+use std::str::FromStr;
+use std::time::Duration;
+
+use super::storage_sql::{ Dialect, SqlQuery, StorageSql };
+use crate::config::ConfigDatabase;
+use crate::{ log_info };
+
+
+static MIGRATOR: Migrator = sqlx::migrate!("./migrations/sqlite");
+
+
+/**
+* SQLite backend, for running on a laptop without a database server. Dates are stored as ISO 8601 text. Fact values go
+* into NUMERIC columns so they compare, sort and aggregate as numbers like on MySQL and PostgreSQL. SQLite keeps an
+* integral value as a 64-bit integer, exactly, and anything else as a 64-bit float, so values with more than 15
+* significant digits are rounded.
+*/
+pub type StorageSqlite = StorageSql<Sqlite>;
+
+
+impl StorageSql<Sqlite>
+{
+	pub async fn connect(config_database: &ConfigDatabase, database_url: &str) -> Result<Self, Box<dyn std::error::Error>>
+	{
+		log_info!("Creating connection to SQLite Database..");
+
+		// The database file is created on first use so `migrate up` can stand up a fresh one
+		let connect_options = SqliteConnectOptions::from_str(database_url)?.create_if_missing(true);
+
+		let mut pool_options = SqlitePoolOptions::new().max_connections(
+			config_database.max_connections
+		).min_connections(
+			config_database.min_connections
+		).acquire_timeout(
+			Duration::from_secs(config_database.acquire_timeout_seconds)
+		);
+
+		// Every connection to sqlite::memory: opens its own empty database, so keep exactly one open for the pool's life
+		if database_url.contains(":memory:") || database_url.contains("mode=memory")
+		{
+			pool_options = pool_options.max_connections(1).min_connections(1).idle_timeout(None).max_lifetime(None);
+		}
+
+		let pool = pool_options.connect_with(connect_options).await?;
+
+		Ok(Self { pool })
+	}
+}
+
+
+#[async_trait]
+impl Dialect for Sqlite
+{
+	fn migrator() -> &'static Migrator
+	{
+		&MIGRATOR
+	}
+
+	fn quote(identifier: &str) -> String
+	{
+		format!("\"{}\"", identifier)
+	}
+
+	fn upsert(conflict_columns: &[&str], update_columns: &[&str]) -> String
+	{
+		let assignments: Vec<String> = update_columns.iter().map(|c| format!("{} = EXCLUDED.{}", c, c)).collect();
+
+		format!("ON CONFLICT ({}) DO UPDATE SET {}", conflict_columns.join(", "), assignments.join(", "))
+	}
+
+	// sqlx has no Decimal encoding for SQLite
+	fn bind_decimal(query: SqlQuery<'_, Self>, val: Decimal) -> SqlQuery<'_, Self>
+	{
+		query.bind(val.to_string())
+	}
+
+	async fn execute_returning_id(pool: &Pool<Self>, query: SqlQuery<'_, Self>) -> Result<i64, sqlx::Error>
+	{
+		Ok(query.execute(pool).await?.last_insert_rowid())
+	}
+
+	fn rows_affected(result: &SqliteQueryResult) -> u64
+	{
+		result.rows_affected()
+	}
+}
+
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	use crate::database::storage::Storage;
+	use crate::database::table_filing_legacy::FilingLegacyTable;
+	use crate::schema::Fact;
+
+
+	async fn memory_storage() -> StorageSqlite
+	{
+		let storage = StorageSqlite::connect(&ConfigDatabase::default(), "sqlite::memory:").await.unwrap();
+
+		storage.migrations_run().await.unwrap();
+
+		storage
+	}
+
+	fn fact(val: &str) -> Fact
+	{
+		Fact {
+			canonical_concept: String::from("us-gaap:Assets"),
+			taxonomy: String::from("us-gaap"),
+			concept: String::from("Assets"),
+			security_filing_accession_number: String::from("0000000001-24-000001"),
+			unit: String::from("USD"),
+			start: None,
+			end: String::from("2023-12-31"),
+			fp: String::from("FY"),
+			fy: 2023,
+			val: Decimal::from_str(val).unwrap(),
+			filed: Some(String::from("2024-02-01")),
+			form: Some(String::from("10-K")),
+			frame: Some(String::from("CY2023Q4I")),
+		}
+	}
+
+	#[tokio::test]
+	async fn migrations_run_up_and_down_on_a_memory_database()
+	{
+		let storage = memory_storage().await;
+
+		let migrations: usize = MIGRATOR.iter().filter(|m| m.migration_type.is_up_migration()).count();
+
+		assert_eq!(storage.migrations_applied().await.unwrap().len(), migrations);
+
+		storage.migrations_undo(0).await.unwrap();
+
+		assert!(storage.migrations_applied().await.unwrap().is_empty());
+
+		storage.migrations_run().await.unwrap();
+
+		assert_eq!(storage.migrations_applied().await.unwrap().len(), migrations);
+	}
+
+	#[tokio::test]
+	async fn fact_values_read_back_exactly_at_a_fixed_scale()
+	{
+		let storage = memory_storage().await;
+
+		for val in ["1234567890123", "-42", "1234567.1234", "0.25"]
+		{
+			let fact: Fact = fact(val);
+
+			storage.filing_fact_create_row(&fact).await.unwrap();
+			storage.filing_legacy_create_row(FilingLegacyTable::Assets, &fact).await.unwrap();
+		}
+
+		for table in ["filing_fact", "filing_assets"]
+		{
+			let vals: Vec<String> = sqlx::query_scalar(
+				&format!("SELECT CAST(val AS TEXT) FROM {} ORDER BY id", table)
+			).fetch_all(
+				&storage.pool
+			).await.unwrap();
+
+			assert_eq!(vals, ["1234567890123", "-42", "1234567.1234", "0.25"], "{}", table);
+		}
+	}
+
+	#[tokio::test]
+	async fn fact_values_compare_as_numbers()
+	{
+		let storage = memory_storage().await;
+
+		for val in ["9", "10", "9.5"]
+		{
+			storage.filing_fact_create_row(&fact(val)).await.unwrap();
+		}
+
+		let max: String = sqlx::query_scalar("SELECT CAST(MAX(val) AS TEXT) FROM filing_fact").fetch_one(
+			&storage.pool
+		).await.unwrap();
+
+		assert_eq!(max, "10");
+
+		let vals: Vec<String> = sqlx::query_scalar(
+			"SELECT CAST(val AS TEXT) FROM filing_fact WHERE val > 9 ORDER BY val"
+		).fetch_all(
+			&storage.pool
+		).await.unwrap();
+
+		assert_eq!(vals, ["9.5", "10"]);
+	}
+}
@@ -29,30 +29,14 @@ impl TableAsset
 		asset_type: &str
 	) -> Result<AssetRow, Box<dyn std::error::Error>>
 	{
-		let result = sqlx::query("INSERT INTO asset (industry, sector, name, `type`) VALUES (?, ?, ?, ?)").bind(
-			industry
-		).bind(
-			sector
-		).bind(
-			name
-		).bind(
-			asset_type
-		).execute(
-			self.db_connection.pool()
-		).await?;
-
-		// Get auto-generated ID
-		let id = result.last_insert_id() as i64;
-
-		// Fetch and return the inserted row
-		let asset = sqlx::query_as::<_, AssetRow>(
-			"SELECT * FROM asset WHERE id = ?"
-		).bind(
-			id
-		).fetch_one(
-			self.db_connection.pool()
-		).await?;
+		self.db_connection.storage().asset_create_row(industry, sector, name, asset_type).await
+	}
 
-		Ok(asset)
+	/**
+	* Keep the display name of an asset current, e.g. after a company rename
+	*/
+	pub async fn update_name(&self, id: i32, name: &str) -> Result<(), Box<dyn std::error::Error>>
+	{
+		self.db_connection.storage().asset_update_name(id, name).await
 	}
 }
@@ -0,0 +1,40 @@
+use std::sync::Arc;
+
+use super::database_connection::DatabaseConnection;
+use crate::schema::Fact;
+
+use sqlx::FromRow;
+
+
+#[derive(Debug, FromRow)]
+pub struct RowFilingFact
+{}
+
+
+/**
+* One row per (filing, taxonomy, concept, unit, start, end) for every concept configured in `facts.concepts`.
+* `canonical_concept` groups equivalents, e.g. ifrs-full:Assets facts are stored with canonical_concept us-gaap:Assets.
+*/
+pub struct TableFilingFact
+{
+	db_connection: Arc<DatabaseConnection>,
+}
+
+
+impl TableFilingFact
+{
+	pub fn new(db_connection: Arc<DatabaseConnection>) -> Self
+	{
+		Self { db_connection }
+	}
+
+	pub async fn create_row(&self, fact: &Fact) -> Result<(), Box<dyn std::error::Error>>
+	{
+		self.db_connection.storage().filing_fact_create_row(fact).await
+	}
+
+	pub async fn read_row(&self, fact: &Fact) -> Result<Option<RowFilingFact>, Box<dyn std::error::Error>>
+	{
+		self.db_connection.storage().filing_fact_read_row(fact).await
+	}
+}
@@ -0,0 +1,71 @@
+use std::sync::Arc;
+
+use super::database_connection::DatabaseConnection;
+use crate::schema::Fact;
+
+use sqlx::FromRow;
+
+
+/**
+* The original per-concept tables, populated with a fixed US GAAP concept and unit whatever `facts.concepts` says
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FilingLegacyTable
+{
+	Assets,
+	CommonStockSharesOutstanding,
+	EntityCommonStockSharesOutstanding,
+}
+
+
+impl FilingLegacyTable
+{
+	pub fn name(&self) -> &'static str
+	{
+		match self
+		{
+			FilingLegacyTable::Assets => "filing_assets",
+			FilingLegacyTable::CommonStockSharesOutstanding => "filing_common_stock_shares_outstanding",
+			FilingLegacyTable::EntityCommonStockSharesOutstanding => "filing_entity_common_stock_shares_outstanding",
+		}
+	}
+
+	/**
+	* filing_assets keeps one row per filing and period end, the shares outstanding tables one row per filing
+	*/
+	pub fn keyed_by_end(&self) -> bool
+	{
+		*self == FilingLegacyTable::Assets
+	}
+}
+
+
+#[derive(Debug, FromRow)]
+pub struct RowFilingLegacy
+{}
+
+
+pub struct TableFilingLegacy
+{
+	db_connection: Arc<DatabaseConnection>,
+	table: FilingLegacyTable,
+}
+
+
+impl TableFilingLegacy
+{
+	pub fn new(db_connection: Arc<DatabaseConnection>, table: FilingLegacyTable) -> Self
+	{
+		Self { db_connection, table }
+	}
+
+	pub async fn create_row(&self, fact: &Fact) -> Result<(), Box<dyn std::error::Error>>
+	{
+		self.db_connection.storage().filing_legacy_create_row(self.table, fact).await
+	}
+
+	pub async fn read_row(&self, fact: &Fact) -> Result<Option<RowFilingLegacy>, Box<dyn std::error::Error>>
+	{
+		self.db_connection.storage().filing_legacy_read_row(self.table, fact).await
+	}
+}
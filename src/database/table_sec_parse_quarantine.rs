@@ -0,0 +1,49 @@
+use std::sync::Arc;
+use sqlx::FromRow;
+
+use super::database_connection::DatabaseConnection;
+use crate::schema::ParseError;
+
+
+#[derive(Debug, FromRow)]
+pub struct TableSecParseQuarantineRow
+{
+	pub archive: String,
+	pub file_name: String,
+	pub field: String,
+	pub item_index: Option<i64>,
+	pub raw_value: Option<String>,
+	pub message: String,
+	pub attempts: i32,
+}
+
+/**
+* Files of a SEC archive that failed to parse. A row stays until the file parses on a later run.
+*/
+pub struct TableSecParseQuarantine
+{
+	db_connection: Arc<DatabaseConnection>,
+}
+
+impl TableSecParseQuarantine
+{
+	pub fn new(db_connection: Arc<DatabaseConnection>) -> Self
+	{
+		Self { db_connection }
+	}
+
+	pub async fn create_row(&self, archive: &str, parse_error: &ParseError) -> Result<(), Box<dyn std::error::Error>>
+	{
+		self.db_connection.storage().sec_parse_quarantine_create_row(archive, parse_error).await
+	}
+
+	pub async fn delete_row(&self, archive: &str, file_name: &str) -> Result<u64, Box<dyn std::error::Error>>
+	{
+		self.db_connection.storage().sec_parse_quarantine_delete_row(archive, file_name).await
+	}
+
+	pub async fn read_rows(&self) -> Result<Vec<TableSecParseQuarantineRow>, Box<dyn std::error::Error>>
+	{
+		self.db_connection.storage().sec_parse_quarantine_read_rows().await
+	}
+}
@@ -21,37 +21,42 @@ impl TableSecSubmissionFileHash
 		Self { db_connection }
 	}
 
-	pub async fn create_row(&self, submission_file_name: &str, hash: &str,) -> Result<(), Box<dyn std::error::Error>>
+	pub async fn create_row(
+		&self,
+		archive: &str,
+		submission_file_name: &str,
+		crc32: u32,
+		uncompressed_size: u64,
+		hash: Option<&str>,
+	) -> Result<(), Box<dyn std::error::Error>>
 	{
-		sqlx::query(
-			"INSERT INTO sec_submission_file_hash (submission_file_name, hash) VALUES (?, ?) ON DUPLICATE KEY UPDATE hash = VALUES(hash);"
-		).bind(
-			submission_file_name
-		).bind(
+		self.db_connection.storage().sec_submission_file_hash_create_row(
+			archive,
+			submission_file_name,
+			crc32,
+			uncompressed_size,
 			hash
-		).execute(
-			self.db_connection.pool()
-		).await?;
-
-		Ok(())
+		).await
 	}
 
+	/**
+	* Find the row matching a file fingerprint. The SHA-256 is only compared when one is given (strict mode).
+	*/
 	pub async fn read_row(
 		&self,
+		archive: &str,
 		submission_file_name: &str,
-		hash: &str,
+		crc32: u32,
+		uncompressed_size: u64,
+		hash: Option<&str>,
 	) -> Result<Option<TableSecSubmissionFileHashRow>, Box<dyn std::error::Error>>
 	{
-		let result = sqlx::query_as::<_, TableSecSubmissionFileHashRow>(
-			"SELECT * FROM sec_submission_file_hash WHERE submission_file_name = ? AND hash = ?;"
-		).bind(
-			submission_file_name
-		).bind(
+		self.db_connection.storage().sec_submission_file_hash_read_row(
+			archive,
+			submission_file_name,
+			crc32,
+			uncompressed_size,
 			hash
-		).fetch_optional(
-			self.db_connection.pool()
-		).await?;
-
-		Ok(result)
+		).await
 	}
 }
@@ -6,6 +6,7 @@ use super::database_connection::DatabaseConnection;
 #[derive(Debug, FromRow)]
 pub struct SecurityRow
 {
+	pub asset_id: i32,
 }
 
 pub struct TableSecurity
@@ -23,13 +24,10 @@ impl TableSecurity
 
 	pub async fn get_by_cik(&self, cik: &str) -> Result<Option<SecurityRow>, Box<dyn std::error::Error>>
 	{
-		let row = sqlx::query_as::<_, SecurityRow>("SELECT cik FROM security WHERE cik = ?").bind(cik).fetch_optional(
-			&*self.db_connection.pool()
-		).await?;
-
-		Ok(row)
+		self.db_connection.storage().security_get_by_cik(cik).await
 	}
 
+	#[allow(clippy::too_many_arguments)]
 	pub async fn create_row(
 		&self,
 		asset_id: i32,
@@ -38,75 +36,61 @@ impl TableSecurity
 		business_state: &str,
 		business_street1: &str,
 		business_zip: &str,
+		mailing_city: &str,
+		mailing_country: &str,
+		mailing_state: &str,
+		mailing_street1: &str,
+		mailing_zip: &str,
+		category: &str,
 		cik: &str,
 		description: &str,
 		ein: &str,
 		entity_type: &str,
+		fiscal_year_end: &str,
+		flags: &str,
+		insider_transaction_for_issuer_exists: bool,
+		insider_transaction_for_owner_exists: bool,
+		investor_website: &str,
+		owner_org: &str,
 		phone: &str,
 		sic: &str,
+		sic_description: &str,
+		state_of_incorporation: &str,
 		website: &str,
 	) -> Result<SecurityRow, Box<dyn std::error::Error>>
 	{
-		sqlx::query(
-			r#"
-				INSERT INTO security (
-					asset_id,
-					business_street1,
-					business_city,
-					business_country,
-					business_state,
-					business_zip,
-					cik,
-					description,
-					ein,
-					entity_type,
-					phone,
-					sic,
-					website
-				) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
-			"#
-		).bind(
-			asset_id
-		).bind(
-			business_street1
-		).bind(
-			business_city
-		).bind(
-			business_country
-		).bind(
-			business_state
-		).bind(
-			business_zip
-		).bind(
-			cik
-		).bind(
-			description
-		).bind(
-			ein
-		).bind(
-			entity_type
-		).bind(
-			phone
-		).bind(
-			sic
-		).bind(
+		self.db_connection.storage().security_create_row(
+			asset_id,
+			business_city,
+			business_country,
+			business_state,
+			business_street1,
+			business_zip,
+			mailing_city,
+			mailing_country,
+			mailing_state,
+			mailing_street1,
+			mailing_zip,
+			category,
+			cik,
+			description,
+			ein,
+			entity_type,
+			fiscal_year_end,
+			flags,
+			insider_transaction_for_issuer_exists,
+			insider_transaction_for_owner_exists,
+			investor_website,
+			owner_org,
+			phone,
+			sic,
+			sic_description,
+			state_of_incorporation,
 			website
-		).execute(
-			self.db_connection.pool()
-		).await?;
-
-		// Fetch and return the inserted row
-		let security = sqlx::query_as::<_, SecurityRow>(
-			"SELECT * FROM security WHERE cik = ?"
-		).bind(
-	   		cik
-		).fetch_one(
-			self.db_connection.pool()
-		).await?;
-
-		Ok(security)
+		).await
 	}
 
+	#[allow(clippy::too_many_arguments)]
 	pub async fn update_row(
 		&self,
 		cik: &str,
@@ -115,69 +99,55 @@ impl TableSecurity
 		business_state: &str,
 		business_street1: &str,
 		business_zip: &str,
+		mailing_city: &str,
+		mailing_country: &str,
+		mailing_state: &str,
+		mailing_street1: &str,
+		mailing_zip: &str,
+		category: &str,
 		description: &str,
 		ein: &str,
 		entity_type: &str,
+		fiscal_year_end: &str,
+		flags: &str,
+		insider_transaction_for_issuer_exists: bool,
+		insider_transaction_for_owner_exists: bool,
+		investor_website: &str,
+		owner_org: &str,
 		phone: &str,
 		sic: &str,
+		sic_description: &str,
+		state_of_incorporation: &str,
 		website: &str,
 	) -> Result<SecurityRow, Box<dyn std::error::Error>>
 	{
-		sqlx::query(
-			r#"
-				UPDATE security
-				SET
-					business_city = ?,
-					business_country = ?,
-					business_state = ?,
-					business_street1 = ?,
-					business_zip = ?,
-					description = ?,
-					ein = ?,
-					entity_type = ?,
-					phone = ?,
-					sic = ?,
-					website = ?
-				WHERE
-					cik = ?
-			"#
-		).bind(
-			business_city
-		).bind(
-			business_country
-		).bind(
-			business_state
-		).bind(
-			business_street1
-		).bind(
-			business_zip
-		).bind(
-			description
-		).bind(
-			ein
-		).bind(
-			entity_type
-		).bind(
-			phone
-		).bind(
-			sic
-		).bind(
+		self.db_connection.storage().security_update_row(
+			cik,
+			business_city,
+			business_country,
+			business_state,
+			business_street1,
+			business_zip,
+			mailing_city,
+			mailing_country,
+			mailing_state,
+			mailing_street1,
+			mailing_zip,
+			category,
+			description,
+			ein,
+			entity_type,
+			fiscal_year_end,
+			flags,
+			insider_transaction_for_issuer_exists,
+			insider_transaction_for_owner_exists,
+			investor_website,
+			owner_org,
+			phone,
+			sic,
+			sic_description,
+			state_of_incorporation,
 			website
-		).bind(
-			cik
-		).execute(
-			self.db_connection.pool()
-		).await?;
-
-		// Fetch and return the updated row
-		let security = sqlx::query_as::<_, SecurityRow>(
-			"SELECT * FROM security WHERE cik = ?"
-		).bind(
-	   		cik
-		).fetch_one(
-			self.db_connection.pool()
-		).await?;
-
-		Ok(security)
+		).await
 	}
 }
@@ -1,9 +1,26 @@
 use std::sync::Arc;
-use sqlx::mysql::MySqlRow;
+use chrono::NaiveDate;
+use sqlx::FromRow;
 
 use super::database_connection::DatabaseConnection;
 
 
+/**
+* A period during which a CIK traded under a ticker on an exchange. `valid_to` is exclusive and None while the pair is
+* still listed, `exchange` is None when the exchange is unknown.
+*/
+#[derive(Debug, FromRow)]
+pub struct SecurityExchangeTickerRow
+{
+	pub id: i64,
+	pub security_cik: String,
+	pub exchange: Option<String>,
+	pub ticker: String,
+	pub valid_from: Option<NaiveDate>,
+	pub valid_to: Option<NaiveDate>,
+}
+
+
 pub struct TableSecurityExchangeTicker
 {
 	db_connection: Arc<DatabaseConnection>,
@@ -16,66 +33,56 @@ impl TableSecurityExchangeTicker
 		Self { db_connection }
 	}
 
-	pub async fn read_rows(&self, security_cik: &str) -> Result<Vec<MySqlRow>, Box<dyn std::error::Error>>
+	pub async fn read_rows(&self, security_cik: &str) -> Result<Vec<SecurityExchangeTickerRow>, Box<dyn std::error::Error>>
 	{
-		let existing_rows = sqlx::query("SELECT * FROM security_exchange_ticker WHERE security_cik = ?").bind(
-			security_cik
-		).fetch_all(
-			self.db_connection.pool()
-		).await?;
-
-		Ok(existing_rows)
+		self.db_connection.storage().security_exchange_ticker_read_rows(security_cik).await
 	}
 
-	pub async fn find_rows(
+	pub async fn create_row(
 		&self,
 		security_cik: &str,
-		exchange: &str,
+		exchange: Option<&str>,
 		ticker: &str,
-	) -> Result<Vec<MySqlRow>, Box<dyn std::error::Error>>
+		valid_from: &NaiveDate,
+	) -> Result<(), Box<dyn std::error::Error>>
 	{
-		let existing_rows = sqlx::query(
-			"SELECT * FROM security_exchange_ticker WHERE security_cik = ? AND exchange = ? AND ticker = ?"
-		).bind(
-			security_cik
-		).bind(
-			exchange
-		).bind(
-			ticker
-		).fetch_all(
-			self.db_connection.pool()
-		).await?;
-
-		Ok(existing_rows)
+		self.db_connection.storage().security_exchange_ticker_create_row(
+			security_cik,
+			exchange,
+			ticker,
+			valid_from
+		).await
 	}
 
-	pub async fn create_row(
+
+	/**
+	* End the period of a pair that is no longer listed. The row is kept so history stays queryable.
+	*/
+	pub async fn close_row(
 		&self,
-		security_cik: &str,
-		exchange: &str,
-		ticker: &str,
+		security_exchange_ticker_id: i64,
+		valid_to: &NaiveDate,
 	) -> Result<(), Box<dyn std::error::Error>>
 	{
-		sqlx::query("INSERT INTO security_exchange_ticker (security_cik, exchange, ticker) VALUES (?, ?, ?)").bind(
-			security_cik
-		).bind(
-			exchange
-		).bind(
-			ticker
-		).execute(
-			self.db_connection.pool()
-		).await?;
-
-		Ok(())
+		self.db_connection.storage().security_exchange_ticker_close_row(
+			security_exchange_ticker_id,
+			valid_to
+		).await
 	}
 
-
-	pub async fn delete_row(&self, security_exchange_ticker_id: i64) -> Result<(), Box<dyn std::error::Error>>
+	/**
+	* Periods of `ticker` that cover `date`. Rows stored before periods were tracked have no valid_from and count as
+	* valid since forever.
+	*/
+	pub async fn find_rows_by_ticker_on_date(
+		&self,
+		ticker: &str,
+		date: &NaiveDate,
+	) -> Result<Vec<SecurityExchangeTickerRow>, Box<dyn std::error::Error>>
 	{
-		sqlx::query("DELETE FROM security_exchange_ticker WHERE id = ?").bind(security_exchange_ticker_id).execute(
-			self.db_connection.pool()
-		).await?;
-
-		Ok(())
+		self.db_connection.storage().security_exchange_ticker_find_rows_by_ticker_on_date(
+			ticker,
+			date
+		).await
 	}
 }
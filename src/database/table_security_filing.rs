@@ -9,7 +9,9 @@ use sqlx::FromRow;
 
 #[derive(Debug, FromRow)]
 pub struct RowSecurityFiling
-{}
+{
+	pub primary_document: Option<String>,
+}
 
 
 pub struct TableSecurityFiling
@@ -25,6 +27,7 @@ impl TableSecurityFiling
 		Self { db_connection }
 	}
 
+	#[allow(clippy::too_many_arguments)]
 	pub async fn create_row(
 		&self,
 		security_cik: &str,
@@ -33,45 +36,73 @@ impl TableSecurityFiling
 		filing_date: &NaiveDate,
 		report_date: &Option<NaiveDate>,
 		acceptance: &NaiveDateTime,
+		act: &str,
+		file_number: &str,
+		film_number: &str,
+		items: &str,
+		size: Option<i64>,
+		is_xbrl: bool,
+		is_inline_xbrl: bool,
+		primary_document: &str,
+		primary_doc_description: &str,
 	) -> Result<(), Box<dyn std::error::Error>>
 	{
-		sqlx::query(
-			r#"
-				INSERT INTO security_filing (security_cik, accession_number, form, filing_date, report_date, acceptance)
-				VALUES (?, ?, ?, ?, ?, ?)
-			"#,
-		).bind(
-			security_cik
-		).bind(
-			accession_number
-		).bind(
-			form
-		).bind(
-			filing_date
-		).bind(
-			report_date
-		).bind(
-			acceptance
-		).execute(
-			self.db_connection.pool()
-		).await?;
+		self.db_connection.storage().security_filing_create_row(
+			security_cik,
+			accession_number,
+			form,
+			filing_date,
+			report_date,
+			acceptance,
+			act,
+			file_number,
+			film_number,
+			items,
+			size,
+			is_xbrl,
+			is_inline_xbrl,
+			primary_document,
+			primary_doc_description
+		).await
+	}
 
-		Ok(())
+	/**
+	* Fill in the document columns of a filing stored before they were ingested
+	*/
+	#[allow(clippy::too_many_arguments)]
+	pub async fn update_row(
+		&self,
+		accession_number: &str,
+		act: &str,
+		file_number: &str,
+		film_number: &str,
+		items: &str,
+		size: Option<i64>,
+		is_xbrl: bool,
+		is_inline_xbrl: bool,
+		primary_document: &str,
+		primary_doc_description: &str,
+	) -> Result<(), Box<dyn std::error::Error>>
+	{
+		self.db_connection.storage().security_filing_update_row(
+			accession_number,
+			act,
+			file_number,
+			film_number,
+			items,
+			size,
+			is_xbrl,
+			is_inline_xbrl,
+			primary_document,
+			primary_doc_description
+		).await
 	}
 
 	pub async fn read_row(
-			&self,
-			accession_number: &str,
-		) -> Result<Option<RowSecurityFiling>, Box<dyn std::error::Error>>
-		{
-			let existing_row = sqlx::query_as::<_, RowSecurityFiling>(
-				"SELECT * FROM security_filing WHERE accession_number = ?"
-			).bind(
-				accession_number
-			).fetch_optional(
-				self.db_connection.pool()
-			).await?;
-
-			Ok(existing_row)
-		}
+		&self,
+		accession_number: &str,
+	) -> Result<Option<RowSecurityFiling>, Box<dyn std::error::Error>>
+	{
+		self.db_connection.storage().security_filing_read_row(accession_number).await
+	}
 }
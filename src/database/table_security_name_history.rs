@@ -0,0 +1,55 @@
+use std::sync::Arc;
+use chrono::NaiveDate;
+use sqlx::FromRow;
+
+use super::database_connection::DatabaseConnection;
+
+
+#[derive(Debug, FromRow)]
+pub struct SecurityNameHistoryRow
+{
+	pub id: i64,
+	pub name: String,
+	pub from_date: Option<NaiveDate>,
+	pub to_date: Option<NaiveDate>,
+}
+
+
+pub struct TableSecurityNameHistory
+{
+	db_connection: Arc<DatabaseConnection>,
+}
+
+impl TableSecurityNameHistory
+{
+	pub fn new(db_connection: Arc<DatabaseConnection>) -> Self
+	{
+		Self { db_connection }
+	}
+
+	pub async fn read_rows(&self, security_cik: &str) -> Result<Vec<SecurityNameHistoryRow>, Box<dyn std::error::Error>>
+	{
+		self.db_connection.storage().security_name_history_read_rows(security_cik).await
+	}
+
+	pub async fn create_row(
+		&self,
+		security_cik: &str,
+		name: &str,
+		from_date: Option<NaiveDate>,
+		to_date: Option<NaiveDate>,
+	) -> Result<(), Box<dyn std::error::Error>>
+	{
+		self.db_connection.storage().security_name_history_create_row(
+			security_cik,
+			name,
+			from_date,
+			to_date
+		).await
+	}
+
+	pub async fn delete_row(&self, security_name_history_id: i64) -> Result<(), Box<dyn std::error::Error>>
+	{
+		self.db_connection.storage().security_name_history_delete_row(security_name_history_id).await
+	}
+}
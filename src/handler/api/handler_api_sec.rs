@@ -1,14 +1,26 @@
-use reqwest::blocking::Client;
-use std::io;
-use std::path::PathBuf;
+use bytes::Bytes;
+use reqwest::{ Response, StatusCode };
+use reqwest::header::{
+	HeaderMap, HeaderValue, CONTENT_RANGE, ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, IF_RANGE, LAST_MODIFIED, RANGE
+};
+use serde::{ Deserialize, Serialize };
+use serde::de::DeserializeOwned;
+use std::ffi::OsString;
+use std::path::{ Path, PathBuf };
+use tokio::io::AsyncWriteExt;
+use tokio::time::timeout;
+use zip::ZipArchive;
 
 use std::fs::{ File, Metadata };
-use std::time::{ Duration, SystemTime };
+use std::time::{ Duration, Instant, SystemTime };
 
+use crate::config::Config;
+use crate::handler::api::handler_api_sec_client::HandlerApiSecClient;
 use crate::handler::file::zip::HandlerFileCompanyfactsZip;
 use crate::handler::file::zip::HandlerFileSubmissionsZip;
+use crate::schema::{ Companyfacts, FactConcept, JsonSubmissions, JsonSubmissionsFilingsArrays, SubmissionsData };
 
-use crate::{ log_info };
+use crate::{ log_debug, log_info, log_warn };
 
 
 pub struct UpdatedSecCompanyfactsAndSubmissions
@@ -17,12 +29,47 @@ pub struct UpdatedSecCompanyfactsAndSubmissions
 	pub handler_file_companyfacts_zip: HandlerFileCompanyfactsZip,
 }
 
+/**
+* HTTP validators of a downloaded archive, stored in a sidecar file next to it
+*/
+#[derive(Debug, Default, Deserialize, Serialize)]
+struct ArchiveValidators
+{
+	etag: Option<String>,
+	last_modified: Option<String>,
+}
+
+
+impl ArchiveValidators
+{
+	fn from_response(response: &Response) -> Self
+	{
+		let get_header = |name| response.headers().get(name).and_then(|v| v.to_str().ok()).map(String::from);
+
+		Self
+		{
+			etag: get_header(ETAG),
+			last_modified: get_header(LAST_MODIFIED),
+		}
+	}
+
+	fn is_empty(&self) -> bool
+	{
+		self.etag.is_none() && self.last_modified.is_none()
+	}
+}
+
+
 pub struct HandlerApiSec
 {
+	client: HandlerApiSecClient,
 	path_dir_tmp: PathBuf,
-	user_agent: &'static str,
-	request_url_companyfacts_zip: &'static str,
-	request_url_submissions_zip: &'static str,
+	request_url_companyfacts_zip: String,
+	request_url_submissions_zip: String,
+	request_url_data: String,
+	read_timeout: Duration,
+	staleness: Duration,
+	fact_concepts: Vec<FactConcept>,
 }
 
 
@@ -31,148 +78,607 @@ impl HandlerApiSec
 	pub const COMPANY_FACTS_ZIP: &'static str = "companyfacts.zip";
 	pub const SUBMISSIONS_ZIP: &'static str = "submissions.zip";
 
-	const SECONDS_24_HOURS: Duration = Duration::from_secs(24 * 60 * 60);
+	const PREFIX_OLD: &'static str = "old.";
+	const SUFFIX_PART: &'static str = ".part";
+	const SUFFIX_VALIDATORS: &'static str = ".validators.json";
+
+	const PROGRESS_INTERVAL: Duration = Duration::from_secs(10);
+
+	const BYTES_PER_MB: f64 = 1024.0 * 1024.0;
 
 
 	/**
 	* @visibility: Public
 	* Constructor for SecurityProfileBuilder
 	*/
-	pub fn new() -> Self
+	pub fn new(config: &Config) -> Result<Self, Box<dyn std::error::Error>>
 	{
-		Self
+		Ok(Self
 		{
-			path_dir_tmp: PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(".tmp"),
-			user_agent: "https://www.yieldsync.xyz w3st.io2021@gmail.com",
-			request_url_companyfacts_zip: "https://www.sec.gov/Archives/edgar/daily-index/xbrl/companyfacts.zip",
-			request_url_submissions_zip: "https://www.sec.gov/Archives/edgar/daily-index/bulkdata/submissions.zip",
-		}
+			client: HandlerApiSecClient::new(
+				&config.sec.user_agent,
+				Duration::from_secs(config.sec.connect_timeout_seconds),
+				Duration::from_secs(config.sec.read_timeout_seconds),
+				config.sec.requests_per_second,
+				config.sec.max_retries,
+			)?,
+			path_dir_tmp: config.data.dir.clone(),
+			request_url_companyfacts_zip: config.sec.request_url_companyfacts_zip.clone(),
+			request_url_submissions_zip: config.sec.request_url_submissions_zip.clone(),
+			request_url_data: config.sec.request_url_data.trim_end_matches('/').to_string(),
+			read_timeout: Duration::from_secs(config.sec.read_timeout_seconds),
+			staleness: Duration::from_secs(config.sec.staleness_hours * 60 * 60),
+			fact_concepts: config.facts.concepts.clone(),
+		})
 	}
 
 
 	/**
 	* @visibility: Internal
-	* Download the company facts zip
+	* Path of the sidecar file holding the HTTP validators for `path`
 	*/
-	fn download_companyfacts_zip(&self) -> Result<(), Box<dyn std::error::Error>>
+	fn path_validators(path: &Path) -> PathBuf
 	{
-		log_info!("Downloading SEC companyfacts.zip..");
+		let mut file_name: OsString = path.file_name().map(OsString::from).unwrap_or_default();
 
-		// Create the directory if it doesn't exist
-		std::fs::create_dir_all(&self.path_dir_tmp)?;
+		file_name.push(Self::SUFFIX_VALIDATORS);
 
-		let client = Client::builder().user_agent(self.user_agent).build().expect("failed to build reqwest client");
+		path.with_file_name(file_name)
+	}
 
-		let mut response = client.get(self.request_url_companyfacts_zip).send()?;
+	/**
+	* @visibility: Internal
+	* Read the stored validators for `path`, if any were saved
+	*/
+	fn load_validators(path: &Path) -> Option<ArchiveValidators>
+	{
+		let contents: String = std::fs::read_to_string(Self::path_validators(path)).ok()?;
 
-		response.error_for_status_ref()?;
+		serde_json::from_str(&contents).ok()
+	}
 
-		let mut output: File = File::create(&self.path_dir_tmp.join(Self::COMPANY_FACTS_ZIP))?;
+	/**
+	* @visibility: Internal
+	* Store the validators for `path` next to it
+	*/
+	fn save_validators(path: &Path, validators: &ArchiveValidators) -> Result<(), Box<dyn std::error::Error>>
+	{
+		std::fs::write(Self::path_validators(path), serde_json::to_string_pretty(validators)?)?;
 
-		io::copy(&mut response, &mut output)?;
+		Ok(())
+	}
 
-		log_info!("Saved to {}", &self.path_dir_tmp.join(Self::COMPANY_FACTS_ZIP).display());
+	/**
+	* @visibility: Internal
+	* Checks if a zip file is older than the staleness window. Only used when the server never gave us validators for
+	* it.
+	*/
+	fn should_download_by_age(&self, file_name: &str) -> Result<bool, Box<dyn std::error::Error>>
+	{
+		let metadata: Metadata = std::fs::metadata(self.path_dir_tmp.join(file_name))?;
 
-		Ok(())
+		let modified: SystemTime = metadata.modified()?;
+
+		let age: Duration = SystemTime::now().duration_since(modified)?;
+
+		if age > self.staleness
+		{
+			log_info!("{} is old and has no stored validators. Redownload needed.", file_name);
+
+			Ok(true)
+		}
+		else
+		{
+			log_info!("{} has age of {} seconds. No need to redownload.", file_name, age.as_secs());
+
+			Ok(false)
+		}
 	}
 
 	/**
 	* @visibility: Internal
-	* Download the submissions zip
+	* Build the request headers for a zip. A partial download is resumed with a Range request, and an existing
+	* archive is revalidated with If-None-Match / If-Modified-Since.
+	* @returns {Option<HeaderMap>} None when the existing archive is fresh enough to skip without asking
 	*/
-	fn download_submissions_zip(&self) -> Result<(), Box<dyn std::error::Error>>
+	fn build_request_headers_zip(
+		&self,
+		file_name: &str,
+		path_file: &Path,
+		path_file_part: &Path,
+		offset: u64,
+	) -> Result<Option<HeaderMap>, Box<dyn std::error::Error>>
 	{
-		log_info!("Downloading SEC submissions.zip..");
+		let mut headers: HeaderMap = HeaderMap::new();
 
-		// Create the directory if it doesn't exist
-		std::fs::create_dir_all(&self.path_dir_tmp)?;
+		if offset > 0
+		{
+			log_info!("Found partial download of {} ({} bytes). Attempting to resume..", file_name, offset);
 
-		let client = Client::builder().user_agent(self.user_agent).build().expect("failed to build reqwest client");
+			headers.insert(RANGE, HeaderValue::from_str(&format!("bytes={}-", offset))?);
 
-		let mut response = client.get(self.request_url_submissions_zip).send()?;
+			// Only resume if the remote file is still the one the partial download came from
+			if let Some(validators_part) = Self::load_validators(path_file_part)
+			{
+				if let Some(if_range) = validators_part.etag.or(validators_part.last_modified)
+				{
+					headers.insert(IF_RANGE, HeaderValue::from_str(&if_range)?);
+				}
+			}
+		}
+		else if path_file.exists()
+		{
+			match Self::load_validators(path_file)
+			{
+				Some(validators) if !validators.is_empty() =>
+				{
+					if let Some(etag) = validators.etag
+					{
+						headers.insert(IF_NONE_MATCH, HeaderValue::from_str(&etag)?);
+					}
 
-		response.error_for_status_ref()?;
+					if let Some(last_modified) = validators.last_modified
+					{
+						headers.insert(IF_MODIFIED_SINCE, HeaderValue::from_str(&last_modified)?);
+					}
+				},
+				_ =>
+				{
+					if !self.should_download_by_age(file_name)?
+					{
+						return Ok(None);
+					}
+				},
+			}
+		}
+		else
+		{
+			log_info!("{} is nonexistant.", file_name);
+		}
+
+		Ok(Some(headers))
+	}
+
+	/**
+	* @visibility: Internal
+	* First byte position of a 206 response, from a `Content-Range: bytes <start>-<end>/<total>` header
+	*/
+	fn content_range_start(response: &Response) -> Option<u64>
+	{
+		let content_range: &str = response.headers().get(CONTENT_RANGE)?.to_str().ok()?;
 
-		let mut output: File = File::create(&self.path_dir_tmp.join(Self::SUBMISSIONS_ZIP))?;
+		let (start, _) = content_range.trim().strip_prefix("bytes ")?.split_once('-')?;
 
-		io::copy(&mut response, &mut output)?;
+		start.trim().parse().ok()
+	}
 
-		log_info!("Saved to {}", self.path_dir_tmp.join(Self::SUBMISSIONS_ZIP).display());
+	/**
+	* @visibility: Internal
+	* Remove a partial download, which may already be gone
+	*/
+	async fn remove_file_part(path_file_part: &Path) -> Result<(), std::io::Error>
+	{
+		match tokio::fs::remove_file(path_file_part).await
+		{
+			Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e),
+			_ => Ok(()),
+		}
+	}
 
-		Ok(())
+	/**
+	* @visibility: Internal
+	* Log bytes downloaded, throughput and ETA of a running download
+	*/
+	fn log_download_progress(file_name: &str, downloaded: u64, total: Option<u64>, received: u64, started: Instant)
+	{
+		let elapsed: f64 = started.elapsed().as_secs_f64().max(0.001);
+		let bytes_per_second: f64 = received as f64 / elapsed;
+
+		match total
+		{
+			Some(total) if total > 0 =>
+			{
+				let eta: u64 = if bytes_per_second > 0.0
+				{
+					(total.saturating_sub(downloaded) as f64 / bytes_per_second) as u64
+				}
+				else
+				{
+					0
+				};
+
+				log_info!(
+					"{}: {:.1}/{:.1} MB ({:.1}%) at {:.2} MB/s, ETA {}m {}s",
+					file_name,
+					downloaded as f64 / Self::BYTES_PER_MB,
+					total as f64 / Self::BYTES_PER_MB,
+					downloaded as f64 / total as f64 * 100.0,
+					bytes_per_second / Self::BYTES_PER_MB,
+					eta / 60,
+					eta % 60
+				);
+			},
+			_ =>
+			{
+				log_info!(
+					"{}: {:.1} MB at {:.2} MB/s",
+					file_name,
+					downloaded as f64 / Self::BYTES_PER_MB,
+					bytes_per_second / Self::BYTES_PER_MB
+				);
+			},
+		}
 	}
 
 	/**
 	* @visibility: Internal
-	* Checks if the company facts zip file is older than 24 hours.
+	* Download a zip into `<file_name>.part`, streaming chunks to disk. A 304 skips the download entirely. Once the
+	* length matches Content-Length and the zip central directory can be read, the current file is kept as
+	* `old.<file_name>` and the download is atomically renamed into place.
+	* @returns {bool} Whether a new archive was downloaded
 	*/
-	fn should_download_companyfacts_zip(&self) -> Result<bool, Box<dyn std::error::Error>>
+	async fn download_zip(&self, request_url: &str, file_name: &str) -> Result<bool, Box<dyn std::error::Error>>
 	{
-		let path_companyfacts_zip: PathBuf = self.path_dir_tmp.join(Self::COMPANY_FACTS_ZIP);
+		// Create the directory if it doesn't exist
+		tokio::fs::create_dir_all(&self.path_dir_tmp).await?;
+
+		let path_file: PathBuf = self.path_dir_tmp.join(file_name);
+		let path_file_old: PathBuf = self.path_dir_tmp.join(format!("{}{}", Self::PREFIX_OLD, file_name));
+		let path_file_part: PathBuf = self.path_dir_tmp.join(format!("{}{}", file_name, Self::SUFFIX_PART));
+
+		let mut offset: u64 = tokio::fs::metadata(&path_file_part).await.map(|m| m.len()).unwrap_or(0);
+
+		// Without validators no If-Range can be sent, and the server could append a different file to the partial one
+		if offset > 0 && Self::load_validators(&path_file_part).and_then(|v| v.etag.or(v.last_modified)).is_none()
+		{
+			log_warn!(
+				"Partial download of {} has no validators to resume against. Restarting download from the beginning..",
+				file_name
+			);
+
+			Self::remove_file_part(&path_file_part).await?;
 
-		let metadata: Metadata = match std::fs::metadata(&path_companyfacts_zip)
+			offset = 0;
+		}
+
+		let mut restarted: bool = false;
+
+		let mut response: Response = loop
 		{
-			Ok(m) => m,
-			Err(_) =>
+			let headers: HeaderMap = match self.build_request_headers_zip(
+				file_name,
+				&path_file,
+				&path_file_part,
+				offset
+			)?
 			{
-				log_info!("companyfacts.zip is nonexistant.");
+				Some(headers) => headers,
+				None => return Ok(false),
+			};
 
-				return Ok(true);
+			let response: Response = self.client.get(request_url, headers).await?;
+
+			let restart_reason: Option<String> = if response.status() == StatusCode::RANGE_NOT_SATISFIABLE && offset > 0
+			{
+				Some(format!("Server rejected resume of {}", file_name))
 			}
+			else if response.status() == StatusCode::PARTIAL_CONTENT && Self::content_range_start(&response) != Some(offset)
+			{
+				Some(
+					format!(
+						"Server resumed {} at {:?} instead of byte {}",
+						file_name,
+						Self::content_range_start(&response),
+						offset
+					)
+				)
+			}
+			else
+			{
+				None
+			};
+
+			let Some(restart_reason) = restart_reason else { break response };
+
+			// A server that still gets it wrong from byte 0 would be asked again forever
+			if restarted
+			{
+				return Err(format!("{} after restarting the download from the beginning", restart_reason).into());
+			}
+
+			log_warn!("{}. Restarting download from the beginning..", restart_reason);
+
+			Self::remove_file_part(&path_file_part).await?;
+
+			offset = 0;
+
+			restarted = true;
 		};
 
-		let modified: SystemTime = metadata.modified()?;
+		if response.status() == StatusCode::NOT_MODIFIED
+		{
+			log_info!("{} has not been modified on the server. Skipping download.", file_name);
 
-		let age: Duration = SystemTime::now().duration_since(modified)?;
+			return Ok(false);
+		}
+
+		response.error_for_status_ref()?;
 
-		if age > Self::SECONDS_24_HOURS
+		log_info!("Downloading SEC {}..", file_name);
+
+		let resumed: bool = response.status() == StatusCode::PARTIAL_CONTENT;
+
+		if offset > 0 && !resumed
 		{
-			log_info!("companyfacts.zip is old. Redownload needed.");
+			log_warn!("Server did not resume {}. Restarting download from the beginning..", file_name);
+
+			offset = 0;
+		}
 
-			return Ok(true);
+		let mut output: tokio::fs::File = if resumed
+		{
+			tokio::fs::OpenOptions::new().append(true).open(&path_file_part).await?
 		}
 		else
 		{
-			log_info!("companyfacts.zip has age of {} seconds. No need to redownload.", age.as_secs());
+			Self::save_validators(&path_file_part, &ArchiveValidators::from_response(&response))?;
 
-			return Ok(false);
+			tokio::fs::File::create(&path_file_part).await?
+		};
+
+		let expected_len: Option<u64> = response.content_length().map(|len| len + offset);
+
+		let started: Instant = Instant::now();
+		let mut last_progress: Instant = started;
+		let mut received: u64 = 0;
+
+		loop
+		{
+			let chunk = match timeout(self.read_timeout, response.chunk()).await
+			{
+				Ok(chunk) => chunk?,
+				Err(_) =>
+				{
+					// Keep the partial file so the next run can resume it
+					return Err(
+						format!(
+							"Timed out reading {} after {} seconds without data",
+							file_name,
+							self.read_timeout.as_secs()
+						).into()
+					);
+				},
+			};
+
+			let Some(chunk) = chunk else { break };
+
+			output.write_all(&chunk).await?;
+
+			received += chunk.len() as u64;
+
+			if last_progress.elapsed() >= Self::PROGRESS_INTERVAL
+			{
+				Self::log_download_progress(file_name, offset + received, expected_len, received, started);
+
+				last_progress = Instant::now();
+			}
+		}
+
+		output.flush().await?;
+		output.sync_all().await?;
+
+		let downloaded_len: u64 = tokio::fs::metadata(&path_file_part).await?.len();
+
+		Self::log_download_progress(file_name, downloaded_len, expected_len, received, started);
+
+		if let Some(expected_len) = expected_len
+		{
+			if downloaded_len != expected_len
+			{
+				// Keep the partial file so the next run can resume it
+				return Err(
+					format!(
+						"Incomplete download of {}: expected {} bytes, got {} bytes",
+						file_name,
+						expected_len,
+						downloaded_len
+					).into()
+				);
+			}
+		}
+
+		// Reading the central directory of a large archive is blocking work
+		let path_file_part_verify: PathBuf = path_file_part.clone();
+
+		let verified: Result<(), String> = tokio::task::spawn_blocking(
+			move ||
+			{
+				let file: File = File::open(&path_file_part_verify).map_err(|e| e.to_string())?;
+
+				ZipArchive::new(file).map(|_| ()).map_err(|e| e.to_string())
+			}
+		).await?;
+
+		if let Err(e) = verified
+		{
+			// A complete but unreadable archive cannot be resumed, so start over next time
+			tokio::fs::remove_file(&path_file_part).await?;
+
+			return Err(format!("Downloaded {} is not a valid zip archive: {}", file_name, e).into());
+		}
+
+		if path_file.exists()
+		{
+			tokio::fs::rename(&path_file, &path_file_old).await?;
+
+			log_info!("Kept previous {} as {}", file_name, path_file_old.display());
 		}
+
+		tokio::fs::rename(&path_file_part, &path_file).await?;
+
+		if Self::path_validators(&path_file_part).exists()
+		{
+			tokio::fs::rename(Self::path_validators(&path_file_part), Self::path_validators(&path_file)).await?;
+		}
+		else
+		{
+			// Never leave stale validators behind for a file they do not describe
+			let _ = tokio::fs::remove_file(Self::path_validators(&path_file)).await;
+		}
+
+		log_info!("Saved {} bytes to {}", downloaded_len, path_file.display());
+
+		Ok(true)
 	}
 
 	/**
 	* @visibility: Internal
-	* Checks if the submissions zip file is older than 24 hours.
+	* Download the company facts zip
 	*/
-	fn should_download_submissions_zip(&self) -> Result<bool, Box<dyn std::error::Error>>
+	async fn download_companyfacts_zip(&self) -> Result<bool, Box<dyn std::error::Error>>
 	{
-		let path_submissions_zip: PathBuf = self.path_dir_tmp.join(Self::SUBMISSIONS_ZIP);
+		self.download_zip(&self.request_url_companyfacts_zip, Self::COMPANY_FACTS_ZIP).await
+	}
 
-		let metadata: Metadata = match std::fs::metadata(&path_submissions_zip)
+	/**
+	* @visibility: Internal
+	* Download the submissions zip
+	*/
+	async fn download_submissions_zip(&self) -> Result<bool, Box<dyn std::error::Error>>
+	{
+		self.download_zip(&self.request_url_submissions_zip, Self::SUBMISSIONS_ZIP).await
+	}
+
+
+	/**
+	* @visibility: Public
+	* Normalize a CIK given as `320193`, `0000320193` or `CIK0000320193` into the `CIK##########` form used by
+	* SEC file names
+	*/
+	pub fn cik_to_file_stem(cik: &str) -> Result<String, Box<dyn std::error::Error>>
+	{
+		let digits: &str = cik.trim().trim_start_matches("CIK").trim_end_matches(".json");
+
+		let cik_number: u64 = digits.parse().map_err(|_| format!("Invalid CIK: {}", cik))?;
+
+		if cik_number > 9_999_999_999
 		{
-			Ok(m) => m,
-			Err(_) =>
-			{
-				log_info!("submissions.zip is nonexistant.");
+			return Err(format!("Invalid CIK: {}", cik).into());
+		}
 
-				return Ok(true);
-			}
-		};
+		Ok(format!("CIK{:010}", cik_number))
+	}
 
-		let modified: SystemTime = metadata.modified()?;
+	/**
+	* @visibility: Internal
+	* GET a document from data.sec.gov
+	* @returns {Option<Bytes>} None when the document does not exist
+	*/
+	async fn get_data(&self, path: &str) -> Result<Option<Bytes>, Box<dyn std::error::Error>>
+	{
+		let request_url: String = format!("{}/{}", self.request_url_data, path);
 
-		let age: Duration = SystemTime::now().duration_since(modified)?;
+		log_debug!("GET {}", request_url);
+
+		let mut response: Response = self.client.get(&request_url, HeaderMap::new()).await?;
 
-		if age > Self::SECONDS_24_HOURS
+		if response.status() == StatusCode::NOT_FOUND
 		{
-			log_info!("submissions.zip is old. Redownload needed.");
+			return Ok(None);
+		}
+
+		response.error_for_status_ref()?;
+
+		let mut body: Vec<u8> = Vec::with_capacity(response.content_length().unwrap_or(0) as usize);
 
-			return Ok(true);
+		// The timeout applies to each chunk, so a large body that keeps arriving is never cut off
+		loop
+		{
+			let chunk = match timeout(self.read_timeout, response.chunk()).await
+			{
+				Ok(chunk) => chunk?,
+				Err(_) =>
+				{
+					return Err(
+						format!(
+							"Timed out reading {} after {} seconds without data",
+							request_url,
+							self.read_timeout.as_secs()
+						).into()
+					);
+				},
+			};
+
+			let Some(chunk) = chunk else { break };
+
+			body.extend_from_slice(&chunk);
 		}
-		else
+
+		Ok(Some(Bytes::from(body)))
+	}
+
+	/**
+	* @visibility: Internal
+	* GET a JSON document from data.sec.gov and deserialize it into `T`
+	* @returns {Option<T>} None when the document does not exist
+	*/
+	async fn get_data_json<T: DeserializeOwned>(&self, path: &str) -> Result<Option<T>, Box<dyn std::error::Error>>
+	{
+		match self.get_data(path).await?
 		{
-			log_info!("submissions.zip has age of {} seconds. No need to redownload.", age.as_secs());
+			Some(body) => Ok(Some(serde_json::from_slice(&body)?)),
+			None => Ok(None),
+		}
+	}
 
-			return Ok(false);
+	/**
+	* @visibility: Public
+	* Fetch one company's submissions, including its older filings files, from the data.sec.gov submissions API
+	* @param cik {&str} CIK of the company
+	*/
+	pub async fn get_submissions_data(&self, cik: &str) -> Result<Option<SubmissionsData>, Box<dyn std::error::Error>>
+	{
+		let file_stem: String = Self::cik_to_file_stem(cik)?;
+
+		let json_submission: JsonSubmissions = match self.get_data_json(&format!("submissions/{}.json", file_stem)).await?
+		{
+			Some(json_submission) => json_submission,
+			None => return Ok(None),
+		};
+
+		let mut json_older_filings: Vec<JsonSubmissionsFilingsArrays> = Vec::new();
+
+		for f in HandlerFileSubmissionsZip::older_filings_file_names(&json_submission)
+		{
+			let json_older_filing: JsonSubmissionsFilingsArrays = self.get_data_json(&format!("submissions/{}", f)).await?.ok_or(
+				format!("Older filings file {} listed for {} does not exist", f, file_stem)
+			)?;
+
+			json_older_filings.push(json_older_filing);
+		}
+
+		Ok(
+			Some(
+				HandlerFileSubmissionsZip::parse_submissions_data(&json_submission, &json_older_filings).map_err(
+					|e| e.with_file_name(&format!("{}.json", file_stem))
+				)?
+			)
+		)
+	}
+
+	/**
+	* @visibility: Public
+	* Fetch one company's facts from the data.sec.gov XBRL companyfacts API
+	* @param cik {&str} CIK of the company
+	*/
+	pub async fn get_companyfacts(&self, cik: &str) -> Result<Option<Companyfacts>, Box<dyn std::error::Error>>
+	{
+		let file_stem: String = Self::cik_to_file_stem(cik)?;
+
+		match self.get_data(&format!("api/xbrl/companyfacts/{}.json", file_stem)).await?
+		{
+			Some(body) => Ok(Some(HandlerFileCompanyfactsZip::parse_companyfacts(body.as_ref(), &self.fact_concepts)?)),
+			None => Ok(None),
 		}
 	}
 
@@ -185,20 +691,12 @@ impl HandlerApiSec
 		&self
 	) -> Result<UpdatedSecCompanyfactsAndSubmissions, Box<dyn std::error::Error>>
 	{
-		if self.should_download_companyfacts_zip()?
-		{
-			self.download_companyfacts_zip()?;
-		}
-		else
+		if !self.download_companyfacts_zip().await?
 		{
 			log_info!("companyfacts.zip file is up-to-date. Skipping download.");
 		}
 
-		if self.should_download_submissions_zip()?
-		{
-			self.download_submissions_zip()?;
-		}
-		else
+		if !self.download_submissions_zip().await?
 		{
 			log_info!("submissions.zip file is up-to-date. Skipping download.");
 		}
@@ -222,10 +720,325 @@ impl HandlerApiSec
 }
 
 
-impl Default for HandlerApiSec
+
+#[cfg(test)]
+mod tests
 {
-	fn default() -> Self
+	use super::*;
+	use tokio::io::AsyncReadExt;
+	use tokio::net::TcpListener;
+	use tokio::task::JoinHandle;
+
+
+	#[test]
+	fn cik_to_file_stem_pads_every_accepted_form()
+	{
+		for cik in ["320193", "0000320193", "CIK0000320193", "CIK0000320193.json", " 320193 "]
+		{
+			assert_eq!(HandlerApiSec::cik_to_file_stem(cik).unwrap(), "CIK0000320193", "{}", cik);
+		}
+
+		assert_eq!(HandlerApiSec::cik_to_file_stem("9999999999").unwrap(), "CIK9999999999");
+	}
+
+	#[test]
+	fn cik_to_file_stem_rejects_what_is_not_a_cik()
+	{
+		for cik in ["", "CIK", "AAPL", "-1", "32O193", "10000000000", "CIK0000320193-submissions-001.json"]
+		{
+			assert!(HandlerApiSec::cik_to_file_stem(cik).is_err(), "{}", cik);
+		}
+	}
+
+	/**
+	* A HandlerApiSec over its own empty data directory, with `files` (name, contents) written into it
+	*/
+	fn handler_api_sec_with_files(test_name: &str, files: &[(&str, &str)]) -> (HandlerApiSec, PathBuf)
 	{
-		Self::new()
+		let dir: PathBuf = std::env::temp_dir().join(format!("security-profile-builder-{}-{}", std::process::id(), test_name));
+
+		let _ = std::fs::remove_dir_all(&dir);
+
+		std::fs::create_dir_all(&dir).unwrap();
+
+		for (file_name, contents) in files
+		{
+			std::fs::write(dir.join(file_name), contents).unwrap();
+		}
+
+		let mut config: Config = Config::default();
+
+		config.data.dir = dir.clone();
+
+		(HandlerApiSec::new(&config).unwrap(), dir)
+	}
+
+	fn request_headers_zip(test_name: &str, files: &[(&str, &str)], offset: u64) -> Option<HeaderMap>
+	{
+		let (handler_api_sec, dir) = handler_api_sec_with_files(test_name, files);
+
+		let headers = handler_api_sec.build_request_headers_zip(
+			HandlerApiSec::SUBMISSIONS_ZIP,
+			&dir.join("submissions.zip"),
+			&dir.join("submissions.zip.part"),
+			offset
+		).unwrap();
+
+		std::fs::remove_dir_all(&dir).unwrap();
+
+		headers
+	}
+
+	#[test]
+	fn request_headers_zip_revalidate_an_existing_archive()
+	{
+		let headers = request_headers_zip(
+			"revalidate",
+			&[
+				("submissions.zip", "zip"),
+				(
+					"submissions.zip.validators.json",
+					r#"{"etag": "\"abc\"", "last_modified": "Wed, 01 May 2024 00:00:00 GMT"}"#
+				),
+			],
+			0
+		).unwrap();
+
+		assert_eq!(headers.get(IF_NONE_MATCH).unwrap(), "\"abc\"");
+		assert_eq!(headers.get(IF_MODIFIED_SINCE).unwrap(), "Wed, 01 May 2024 00:00:00 GMT");
+		assert!(headers.get(RANGE).is_none());
+	}
+
+	#[test]
+	fn request_headers_zip_resume_a_partial_download_only_if_unchanged()
+	{
+		let headers = request_headers_zip(
+			"resume",
+			&[
+				("submissions.zip.part", "zi"),
+				("submissions.zip.part.validators.json", r#"{"etag": "\"abc\"", "last_modified": null}"#),
+			],
+			2
+		).unwrap();
+
+		assert_eq!(headers.get(RANGE).unwrap(), "bytes=2-");
+		assert_eq!(headers.get(IF_RANGE).unwrap(), "\"abc\"");
+		assert!(headers.get(IF_NONE_MATCH).is_none());
+	}
+
+	#[test]
+	fn request_headers_zip_skip_a_fresh_archive_without_validators()
+	{
+		assert!(request_headers_zip("fresh", &[("submissions.zip", "zip")], 0).is_none());
+
+		assert!(request_headers_zip("missing", &[], 0).unwrap().is_empty());
+	}
+
+	/**
+	* A canned response: status, headers and body
+	*/
+	type StubResponse = (u16, Vec<(&'static str, String)>, Vec<u8>);
+
+	/**
+	* Serve `responses` (status, headers, body) in order, one per connection, on a local port
+	* Returns the URL to request and a handle yielding the head of every request received
+	*/
+	async fn serve(responses: Vec<StubResponse>) -> (String, JoinHandle<Vec<String>>)
+	{
+		let listener: TcpListener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+
+		let url: String = format!("http://{}/{}", listener.local_addr().unwrap(), HandlerApiSec::SUBMISSIONS_ZIP);
+
+		let requests = tokio::spawn(
+			async move
+			{
+				let mut requests: Vec<String> = Vec::new();
+
+				for (status, headers, body) in responses
+				{
+					let (mut stream, _) = listener.accept().await.unwrap();
+
+					let mut head: Vec<u8> = Vec::new();
+
+					while !head.ends_with(b"\r\n\r\n")
+					{
+						let mut byte = [0u8; 1];
+
+						if stream.read(&mut byte).await.unwrap() == 0
+						{
+							break;
+						}
+
+						head.push(byte[0]);
+					}
+
+					requests.push(String::from_utf8(head).unwrap().to_lowercase());
+
+					let mut response: String = format!(
+						"HTTP/1.1 {} {}\r\nContent-Length: {}\r\nConnection: close\r\n",
+						status,
+						StatusCode::from_u16(status).unwrap().canonical_reason().unwrap_or(""),
+						body.len()
+					);
+
+					for (name, value) in headers
+					{
+						response.push_str(&format!("{}: {}\r\n", name, value));
+					}
+
+					response.push_str("\r\n");
+
+					stream.write_all(response.as_bytes()).await.unwrap();
+					stream.write_all(&body).await.unwrap();
+					stream.shutdown().await.unwrap();
+				}
+
+				requests
+			}
+		);
+
+		(url, requests)
+	}
+
+	/**
+	* A small but valid zip archive
+	*/
+	fn zip_bytes() -> Vec<u8>
+	{
+		let mut writer = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+
+		writer.start_file("CIK0000000001.json", zip::write::FileOptions::default()).unwrap();
+
+		std::io::Write::write_all(&mut writer, br#"{"cik": "1", "name": "Co"}"#).unwrap();
+
+		writer.finish().unwrap().into_inner()
+	}
+
+	fn validators(etag: &str) -> String
+	{
+		format!(r#"{{"etag": "\"{}\"", "last_modified": null}}"#, etag)
+	}
+
+	#[tokio::test]
+	async fn download_zip_keeps_the_archive_on_304()
+	{
+		let (handler_api_sec, dir) = handler_api_sec_with_files(
+			"download-304",
+			&[("submissions.zip", "current"), ("submissions.zip.validators.json", &validators("abc"))]
+		);
+
+		let (url, requests) = serve(vec![(304, vec![], vec![])]).await;
+
+		assert!(!handler_api_sec.download_zip(&url, HandlerApiSec::SUBMISSIONS_ZIP).await.unwrap());
+
+		assert!(requests.await.unwrap()[0].contains("if-none-match: \"abc\""));
+		assert_eq!(std::fs::read_to_string(dir.join("submissions.zip")).unwrap(), "current");
+		assert!(!dir.join("old.submissions.zip").exists());
+
+		std::fs::remove_dir_all(&dir).unwrap();
+	}
+
+	#[tokio::test]
+	async fn download_zip_replaces_the_archive_on_200_and_keeps_the_old_one()
+	{
+		let (handler_api_sec, dir) = handler_api_sec_with_files(
+			"download-200",
+			&[("submissions.zip", "current"), ("submissions.zip.validators.json", &validators("abc"))]
+		);
+
+		let zip: Vec<u8> = zip_bytes();
+
+		let (url, requests) = serve(vec![(200, vec![("ETag", String::from("\"def\""))], zip.clone())]).await;
+
+		assert!(handler_api_sec.download_zip(&url, HandlerApiSec::SUBMISSIONS_ZIP).await.unwrap());
+
+		requests.await.unwrap();
+
+		assert_eq!(std::fs::read(dir.join("submissions.zip")).unwrap(), zip);
+		assert_eq!(std::fs::read_to_string(dir.join("old.submissions.zip")).unwrap(), "current");
+		assert_eq!(HandlerApiSec::load_validators(&dir.join("submissions.zip")).unwrap().etag.unwrap(), "\"def\"");
+		assert!(!dir.join("submissions.zip.part").exists());
+
+		std::fs::remove_dir_all(&dir).unwrap();
+	}
+
+	#[tokio::test]
+	async fn download_zip_appends_a_206_to_the_partial_file()
+	{
+		let zip: Vec<u8> = zip_bytes();
+
+		let (head, tail) = zip.split_at(10);
+
+		let (handler_api_sec, dir) = handler_api_sec_with_files(
+			"download-206",
+			&[("submissions.zip.part.validators.json", &validators("abc"))]
+		);
+
+		std::fs::write(dir.join("submissions.zip.part"), head).unwrap();
+
+		let (url, requests) = serve(
+			vec![
+				(
+					206,
+					vec![("Content-Range", format!("bytes 10-{}/{}", zip.len() - 1, zip.len()))],
+					tail.to_vec()
+				),
+			]
+		).await;
+
+		assert!(handler_api_sec.download_zip(&url, HandlerApiSec::SUBMISSIONS_ZIP).await.unwrap());
+
+		let requests: Vec<String> = requests.await.unwrap();
+
+		assert!(requests[0].contains("range: bytes=10-"));
+		assert!(requests[0].contains("if-range: \"abc\""));
+		assert_eq!(std::fs::read(dir.join("submissions.zip")).unwrap(), zip);
+		assert!(!dir.join("submissions.zip.part").exists());
+		assert_eq!(HandlerApiSec::load_validators(&dir.join("submissions.zip")).unwrap().etag.unwrap(), "\"abc\"");
+
+		std::fs::remove_dir_all(&dir).unwrap();
+	}
+
+	#[tokio::test]
+	async fn download_zip_restarts_once_on_416()
+	{
+		let zip: Vec<u8> = zip_bytes();
+
+		let (handler_api_sec, dir) = handler_api_sec_with_files(
+			"download-416",
+			&[("submissions.zip.part", "stale"), ("submissions.zip.part.validators.json", &validators("abc"))]
+		);
+
+		let (url, requests) = serve(vec![(416, vec![], vec![]), (200, vec![], zip.clone())]).await;
+
+		assert!(handler_api_sec.download_zip(&url, HandlerApiSec::SUBMISSIONS_ZIP).await.unwrap());
+
+		let requests: Vec<String> = requests.await.unwrap();
+
+		assert!(requests[0].contains("range: bytes=5-"));
+		assert!(!requests[1].contains("range:"));
+		assert_eq!(std::fs::read(dir.join("submissions.zip")).unwrap(), zip);
+
+		std::fs::remove_dir_all(&dir).unwrap();
+	}
+
+	#[tokio::test]
+	async fn download_zip_gives_up_when_the_restart_is_resumed_at_the_wrong_byte_too()
+	{
+		let (handler_api_sec, dir) = handler_api_sec_with_files(
+			"download-206-wrong",
+			&[("submissions.zip.part", "stale"), ("submissions.zip.part.validators.json", &validators("abc"))]
+		);
+
+		let wrong_range = || (206, vec![("Content-Range", String::from("bytes 3-9/10"))], b"1234567".to_vec());
+
+		let (url, requests) = serve(vec![wrong_range(), wrong_range()]).await;
+
+		assert!(handler_api_sec.download_zip(&url, HandlerApiSec::SUBMISSIONS_ZIP).await.is_err());
+
+		assert_eq!(requests.await.unwrap().len(), 2);
+		assert!(!dir.join("submissions.zip").exists());
+
+		std::fs::remove_dir_all(&dir).unwrap();
 	}
 }
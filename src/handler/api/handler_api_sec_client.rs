@@ -0,0 +1,342 @@
+use chrono::{ DateTime, Utc };
+use rand::Rng;
+use reqwest::{ Client, Response, StatusCode };
+use reqwest::header::{ HeaderMap, RETRY_AFTER };
+use std::sync::Arc;
+use std::time::{ Duration, Instant };
+use tokio::sync::Mutex;
+use tokio::time::{ sleep, timeout };
+
+use crate::{ log_debug, log_warn };
+
+
+/**
+* Token bucket shared by every request made through a HandlerApiSecClient
+*/
+struct TokenBucket
+{
+	capacity: f64,
+	tokens: f64,
+	refill_per_second: f64,
+	last_refill: Instant,
+}
+
+
+impl TokenBucket
+{
+	fn new(requests_per_second: f64) -> Self
+	{
+		Self
+		{
+			capacity: requests_per_second.max(1.0),
+			tokens: requests_per_second.max(1.0),
+			refill_per_second: requests_per_second,
+			last_refill: Instant::now(),
+		}
+	}
+
+	/**
+	* Take a token if one is available, otherwise return how long until one will be
+	*/
+	fn try_take(&mut self) -> Option<Duration>
+	{
+		let now: Instant = Instant::now();
+
+		let elapsed: f64 = now.duration_since(self.last_refill).as_secs_f64();
+
+		self.tokens = (self.tokens + elapsed * self.refill_per_second).min(self.capacity);
+		self.last_refill = now;
+
+		if self.tokens >= 1.0
+		{
+			self.tokens -= 1.0;
+
+			return None;
+		}
+
+		Some(Duration::from_secs_f64((1.0 - self.tokens) / self.refill_per_second))
+	}
+}
+
+
+/**
+* HTTP client for every SEC endpoint. Keeps us under SEC's fair-access limit with a token bucket and retries
+* 429/503 responses and transient network errors with exponential backoff, jitter and Retry-After.
+*/
+#[derive(Clone)]
+pub struct HandlerApiSecClient
+{
+	client: Client,
+	token_bucket: Arc<Mutex<TokenBucket>>,
+	max_retries: u32,
+	read_timeout: Duration,
+	backoff_base: Duration,
+	backoff_max: Duration,
+}
+
+
+impl HandlerApiSecClient
+{
+	const BACKOFF_BASE: Duration = Duration::from_secs(1);
+	const BACKOFF_MAX: Duration = Duration::from_secs(60);
+
+
+	/**
+	* @visibility: Public
+	* @param read_timeout {Duration} Longest wait for the response headers of a request
+	* @param requests_per_second {f64} Must stay at or below SEC's fair-access limit of 10
+	*/
+	pub fn new(
+		user_agent: &str,
+		connect_timeout: Duration,
+		read_timeout: Duration,
+		requests_per_second: f64,
+		max_retries: u32
+	) -> Result<Self, Box<dyn std::error::Error>>
+	{
+		let client: Client = Client::builder().user_agent(
+			user_agent
+		).connect_timeout(
+			connect_timeout
+		).build()?;
+
+		Ok(Self
+		{
+			client,
+			token_bucket: Arc::new(Mutex::new(TokenBucket::new(requests_per_second))),
+			max_retries,
+			read_timeout,
+			backoff_base: Self::BACKOFF_BASE,
+			backoff_max: Self::BACKOFF_MAX,
+		})
+	}
+
+	/**
+	* @visibility: Internal
+	* Wait until the token bucket lets another request through
+	*/
+	async fn acquire(&self)
+	{
+		loop
+		{
+			let wait: Option<Duration> = self.token_bucket.lock().await.try_take();
+
+			match wait
+			{
+				Some(wait) => sleep(wait).await,
+				None => return,
+			}
+		}
+	}
+
+	/**
+	* @visibility: Internal
+	* Exponential backoff with full jitter for the given attempt
+	*/
+	fn backoff(&self, attempt: u32) -> Duration
+	{
+		let exponential: Duration = self.backoff_base.saturating_mul(2u32.saturating_pow(attempt)).min(self.backoff_max);
+
+		Duration::from_secs_f64(rand::thread_rng().gen_range(0.0..=exponential.as_secs_f64()))
+	}
+
+	/**
+	* @visibility: Internal
+	* Parse a Retry-After header given either in seconds or as an HTTP date
+	*/
+	fn retry_after(headers: &HeaderMap) -> Option<Duration>
+	{
+		let value: &str = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+
+		if let Ok(seconds) = value.parse::<u64>()
+		{
+			return Some(Duration::from_secs(seconds));
+		}
+
+		let date: DateTime<Utc> = DateTime::parse_from_rfc2822(value).ok()?.with_timezone(&Utc);
+
+		(date - Utc::now()).to_std().ok()
+	}
+
+	/**
+	* @visibility: Public
+	* Rate-limited GET with retries. Statuses other than 429/503 are returned to the caller as-is. A server that sends
+	* no response headers within the read timeout is retried like a network error.
+	* @param url {&str} Request URL
+	* @param headers {HeaderMap} Extra request headers
+	*/
+	pub async fn get(&self, url: &str, headers: HeaderMap) -> Result<Response, Box<dyn std::error::Error>>
+	{
+		let mut attempt: u32 = 0;
+
+		loop
+		{
+			self.acquire().await;
+
+			let sent = match timeout(self.read_timeout, self.client.get(url).headers(headers.clone()).send()).await
+			{
+				Ok(sent) => sent,
+				Err(_) =>
+				{
+					if attempt >= self.max_retries
+					{
+						return Err(
+							format!(
+								"Timed out waiting for GET {} after {} seconds without a response",
+								url,
+								self.read_timeout.as_secs()
+							).into()
+						);
+					}
+
+					let wait: Duration = self.backoff(attempt);
+
+					log_warn!("GET {} timed out. Retrying in {:.1}s..", url, wait.as_secs_f64());
+
+					attempt += 1;
+
+					sleep(wait).await;
+
+					continue;
+				},
+			};
+
+			let wait: Duration = match sent
+			{
+				Ok(response) =>
+				{
+					let status: StatusCode = response.status();
+
+					if status != StatusCode::TOO_MANY_REQUESTS && status != StatusCode::SERVICE_UNAVAILABLE
+					{
+						return Ok(response);
+					}
+
+					if attempt >= self.max_retries
+					{
+						return Err(format!("GET {} failed with {} after {} retries", url, status, attempt).into());
+					}
+
+					// A server asking for hours would otherwise stall the whole run
+					let wait: Duration = Self::retry_after(response.headers()).map(
+						|retry_after| retry_after.min(self.backoff_max)
+					).unwrap_or_else(
+						|| self.backoff(attempt)
+					);
+
+					log_warn!("GET {} returned {}. Retrying in {:.1}s..", url, status, wait.as_secs_f64());
+
+					wait
+				},
+				Err(e) if e.is_connect() || e.is_timeout() || e.is_request() =>
+				{
+					if attempt >= self.max_retries
+					{
+						return Err(e.into());
+					}
+
+					let wait: Duration = self.backoff(attempt);
+
+					log_warn!("GET {} failed: {}. Retrying in {:.1}s..", url, e, wait.as_secs_f64());
+
+					wait
+				},
+				Err(e) => return Err(e.into()),
+			};
+
+			attempt += 1;
+
+			log_debug!("Retry {}/{} for GET {}", attempt, self.max_retries, url);
+
+			sleep(wait).await;
+		}
+	}
+}
+
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	use reqwest::header::HeaderValue;
+
+
+	fn retry_after(value: &str) -> Option<Duration>
+	{
+		let mut headers: HeaderMap = HeaderMap::new();
+
+		headers.insert(RETRY_AFTER, HeaderValue::from_str(value).unwrap());
+
+		HandlerApiSecClient::retry_after(&headers)
+	}
+
+	#[test]
+	fn token_bucket_refills_over_time_and_reports_the_wait()
+	{
+		let mut token_bucket: TokenBucket = TokenBucket::new(4.0);
+
+		for _ in 0..4
+		{
+			assert_eq!(token_bucket.try_take(), None);
+		}
+
+		// Empty, a token at 4 per second is at most 250 ms away
+		let wait: Duration = token_bucket.try_take().unwrap();
+
+		assert!(wait > Duration::ZERO && wait <= Duration::from_millis(250), "{:?}", wait);
+
+		// Half a second refills two tokens, and never more than the capacity
+		token_bucket.tokens = 0.0;
+		token_bucket.last_refill = Instant::now() - Duration::from_millis(500);
+
+		assert_eq!(token_bucket.try_take(), None);
+		assert_eq!(token_bucket.try_take(), None);
+		assert!(token_bucket.try_take().is_some());
+
+		token_bucket.last_refill = Instant::now() - Duration::from_secs(60);
+
+		token_bucket.try_take();
+
+		assert!(token_bucket.tokens <= token_bucket.capacity);
+	}
+
+	#[test]
+	fn backoff_stays_within_backoff_max()
+	{
+		let client = HandlerApiSecClient::new("test", Duration::from_secs(1), Duration::from_secs(1), 1.0, 3).unwrap();
+
+		for attempt in [0, 1, 5, 6, 31, 32, 100, u32::MAX]
+		{
+			assert!(client.backoff(attempt) <= client.backoff_max, "attempt {}", attempt);
+		}
+
+		assert!(client.backoff(0) <= client.backoff_base);
+	}
+
+	#[test]
+	fn retry_after_reads_seconds_and_http_dates()
+	{
+		assert_eq!(retry_after("120"), Some(Duration::from_secs(120)));
+		assert_eq!(retry_after(" 0 "), Some(Duration::ZERO));
+
+		let in_a_minute: String = (Utc::now() + chrono::Duration::seconds(60)).to_rfc2822();
+
+		let wait: Duration = retry_after(&in_a_minute).unwrap();
+
+		assert!(wait > Duration::from_secs(55) && wait <= Duration::from_secs(60), "{:?}", wait);
+
+		// A date already past means there is nothing to wait for
+		assert_eq!(retry_after(&(Utc::now() - chrono::Duration::seconds(60)).to_rfc2822()), None);
+	}
+
+	#[test]
+	fn retry_after_ignores_garbage()
+	{
+		for value in ["", "soon", "-5", "1.5", "Mon, 32 Foo 2024 99:00:00 GMT"]
+		{
+			assert_eq!(retry_after(value), None, "{:?}", value);
+		}
+
+		assert_eq!(HandlerApiSecClient::retry_after(&HeaderMap::new()), None);
+	}
+}
@@ -1 +1,2 @@
 pub mod handler_api_sec;
+pub mod handler_api_sec_client;
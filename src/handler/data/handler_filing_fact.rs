@@ -0,0 +1,53 @@
+use std::sync::Arc;
+
+use crate::database::database_connection::DatabaseConnection;
+use crate::schema::Fact;
+
+use crate::{ log_debug, log_superdebug };
+use crate::database::table_filing_fact::TableFilingFact;
+
+
+pub struct HandlerFilingFact
+{
+	table_filing_fact: TableFilingFact,
+}
+
+
+impl HandlerFilingFact
+{
+	/**
+	* @visibility: Public
+	*/
+	pub fn new(db_connection: Arc<DatabaseConnection>) -> Self
+	{
+		Self
+		{
+			table_filing_fact: TableFilingFact::new(db_connection.clone()),
+		}
+	}
+
+	pub async fn synchronize(&self, facts: &Vec<Fact>,) -> Result<(), Box<dyn std::error::Error>>
+	{
+		log_debug!("Synchronizing filing_fact..");
+
+		for f in facts
+		{
+			// Check if the data is already in the database
+			if self.table_filing_fact.read_row(f).await?.is_some()
+			{
+				log_superdebug!(
+					"Row for {}:{} with security_filing_accession_number {} already exists in database",
+					f.taxonomy,
+					f.concept,
+					f.security_filing_accession_number
+				);
+
+				continue;
+			}
+
+			self.table_filing_fact.create_row(f).await?;
+		}
+
+		Ok(())
+	}
+}
@@ -0,0 +1,53 @@
+use std::sync::Arc;
+
+use crate::database::database_connection::DatabaseConnection;
+use crate::schema::Fact;
+
+use crate::{ log_debug, log_superdebug };
+use crate::database::table_filing_legacy::{ FilingLegacyTable, TableFilingLegacy };
+
+
+pub struct HandlerFilingLegacy
+{
+	table: FilingLegacyTable,
+	table_filing_legacy: TableFilingLegacy,
+}
+
+
+impl HandlerFilingLegacy
+{
+	/**
+	* @visibility: Public
+	*/
+	pub fn new(db_connection: Arc<DatabaseConnection>, table: FilingLegacyTable) -> Self
+	{
+		Self
+		{
+			table,
+			table_filing_legacy: TableFilingLegacy::new(db_connection.clone(), table),
+		}
+	}
+
+	pub async fn synchronize(&self, facts: &Vec<Fact>,) -> Result<(), Box<dyn std::error::Error>>
+	{
+		log_debug!("Synchronizing {}..", self.table.name());
+
+		for f in facts
+		{
+			// Check if the data is already in the database
+			if self.table_filing_legacy.read_row(f).await?.is_some()
+			{
+				log_superdebug!(
+					"Row with security_filing_accession_number {} already exists in database",
+					f.security_filing_accession_number
+				);
+
+				continue;
+			}
+
+			self.table_filing_legacy.create_row(f).await?;
+		}
+
+		Ok(())
+	}
+}
@@ -0,0 +1,63 @@
+use std::sync::Arc;
+
+use crate::database::database_connection::DatabaseConnection;
+
+use crate::database::table_sec_parse_quarantine::{ TableSecParseQuarantine, TableSecParseQuarantineRow };
+use crate::schema::{ ParseError, SecArchive };
+
+use crate::{ log_info, log_warn };
+
+
+pub struct HandlerSecParseQuarantine
+{
+	table_sec_parse_quarantine: TableSecParseQuarantine,
+}
+
+
+impl HandlerSecParseQuarantine
+{
+	/**
+	* @visibility: Public
+	*/
+	pub fn new(db_connection: Arc<DatabaseConnection>) -> Self
+	{
+		Self
+		{
+			table_sec_parse_quarantine: TableSecParseQuarantine::new(db_connection.clone()),
+		}
+	}
+
+	/**
+	* @visibility: Public
+	* Record a file that failed to parse. Its fingerprint is not stored, so the next run retries it.
+	*/
+	pub async fn quarantine(&self, archive: SecArchive, parse_error: &ParseError) -> Result<(), Box<dyn std::error::Error>>
+	{
+		log_warn!("[QUARANTINE] {}/{}", archive.as_str(), parse_error);
+
+		self.table_sec_parse_quarantine.create_row(archive.as_str(), parse_error).await
+	}
+
+	/**
+	* @visibility: Public
+	* Remove a file from quarantine once it parsed
+	*/
+	pub async fn release(&self, archive: SecArchive, file_name: &str) -> Result<(), Box<dyn std::error::Error>>
+	{
+		if self.table_sec_parse_quarantine.delete_row(archive.as_str(), file_name).await? > 0
+		{
+			log_info!("{}/{} parsed and was released from quarantine", archive.as_str(), file_name);
+		}
+
+		Ok(())
+	}
+
+	/**
+	* @visibility: Public
+	* Every file currently in quarantine
+	*/
+	pub async fn list(&self) -> Result<Vec<TableSecParseQuarantineRow>, Box<dyn std::error::Error>>
+	{
+		self.table_sec_parse_quarantine.read_rows().await
+	}
+}
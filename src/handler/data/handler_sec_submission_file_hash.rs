@@ -1,8 +1,10 @@
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use crate::database::database_connection::DatabaseConnection;
 
 use crate::database::table_sec_submission_file_hash::{ TableSecSubmissionFileHash };
+use crate::schema::{ FileFingerprint, SecArchive };
 
 use crate::{ log_debug, log_superdebug };
 
@@ -28,37 +30,87 @@ impl HandlerSecSubmissionFileHash
 
 	pub async fn synchronize(
 		&self,
+		archive: SecArchive,
 		submission_file_name: &str,
-		sec_submission_file_hash: &str,
+		file_fingerprint: &FileFingerprint,
 	) -> Result<Vec<i64>, Box<dyn std::error::Error>>
 	{
 		log_debug!("Synchronizing table_sec_submission_file_hash..");
 
-		log_superdebug!("s_file_name: {} s_hash: {}", submission_file_name, sec_submission_file_hash);
+		log_superdebug!("{}/{} fingerprint: {:?}", archive.as_str(), submission_file_name, file_fingerprint);
 
-		self.table_sec_submission_file_hash.create_row(&submission_file_name, &sec_submission_file_hash).await?;
+		self.table_sec_submission_file_hash.create_row(
+			archive.as_str(),
+			submission_file_name,
+			file_fingerprint.crc32,
+			file_fingerprint.size,
+			file_fingerprint.sha256.as_deref(),
+		).await?;
 
 		Ok(vec![])
 	}
 
-	pub async fn hash_exists(
+	pub async fn fingerprint_exists(
 		&self,
+		archive: SecArchive,
 		submission_file_name: &str,
-		sec_submission_file_hash: &str,
+		file_fingerprint: &FileFingerprint,
 	) -> Result<bool, Box<dyn std::error::Error>>
 	{
 		let result = self.table_sec_submission_file_hash.read_row(
-			&submission_file_name,
-			&sec_submission_file_hash,
+			archive.as_str(),
+			submission_file_name,
+			file_fingerprint.crc32,
+			file_fingerprint.size,
+			file_fingerprint.sha256.as_deref(),
 		).await?;
 
-		if let Some(_) = result
+		Ok(result.is_some())
+	}
+
+	/**
+	* Whether every file of a group (a company's main file and its paginated files) was already synchronized with
+	* its current fingerprint. A change in any one of them means the group has to be synchronized again.
+	* @visibility: Public
+	* @param archive {SecArchive} Archive the files belong to
+	* @param file_names_to_fingerprints {&BTreeMap<String, FileFingerprint>} Fingerprints of the group's files
+	*/
+	pub async fn group_fingerprints_exist(
+		&self,
+		archive: SecArchive,
+		file_names_to_fingerprints: &BTreeMap<String, FileFingerprint>,
+	) -> Result<bool, Box<dyn std::error::Error>>
+	{
+		for (file_name, file_fingerprint) in file_names_to_fingerprints
 		{
-			Ok(true)
+			if !self.fingerprint_exists(archive, file_name, file_fingerprint).await?
+			{
+				log_superdebug!("{}/{} fingerprint changed", archive.as_str(), file_name);
+
+				return Ok(false);
+			}
 		}
-		else
+
+		Ok(true)
+	}
+
+	/**
+	* Record the fingerprint of every file of a group once the group was synchronized
+	* @visibility: Public
+	* @param archive {SecArchive} Archive the files belong to
+	* @param file_names_to_fingerprints {&BTreeMap<String, FileFingerprint>} Fingerprints of the group's files
+	*/
+	pub async fn synchronize_group(
+		&self,
+		archive: SecArchive,
+		file_names_to_fingerprints: &BTreeMap<String, FileFingerprint>,
+	) -> Result<(), Box<dyn std::error::Error>>
+	{
+		for (file_name, file_fingerprint) in file_names_to_fingerprints
 		{
-			Ok(false)
+			self.synchronize(archive, file_name, file_fingerprint).await?;
 		}
+
+		Ok(())
 	}
 }
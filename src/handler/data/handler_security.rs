@@ -2,6 +2,8 @@ use std::sync::Arc;
 
 use crate::database::database_connection::DatabaseConnection;
 use crate::database::table_security::TableSecurity;
+use crate::handler::data::handler_security_name_history::HandlerSecurityNameHistory;
+use crate::schema::SubmissionsDataFormerName;
 
 use crate::{ log_debug };
 use crate::database::table_asset::{ AssetRow, TableAsset };
@@ -16,12 +18,27 @@ pub struct SynchronizeSecurity
 	pub business_state: String,
 	pub business_street1: String,
 	pub business_zip: String,
+	pub mailing_country: String,
+	pub mailing_city: String,
+	pub mailing_state: String,
+	pub mailing_street1: String,
+	pub mailing_zip: String,
+	pub category: String,
 	pub description: String,
 	pub ein: String,
 	pub entity_type: String,
+	pub fiscal_year_end: String,
+	pub flags: String,
+	pub former_names: Vec<SubmissionsDataFormerName>,
+	pub insider_transaction_for_issuer_exists: bool,
+	pub insider_transaction_for_owner_exists: bool,
+	pub investor_website: String,
 	pub name: String,
+	pub owner_org: String,
 	pub phone: String,
 	pub sic: String,
+	pub sic_description: String,
+	pub state_of_incorporation: String,
 	pub website: String,
 }
 
@@ -29,7 +46,8 @@ pub struct SynchronizeSecurity
 pub struct HandlerSecurity
 {
 	t_asset: TableAsset,
-	t_security: TableSecurity
+	t_security: TableSecurity,
+	h_security_name_history: HandlerSecurityNameHistory,
 }
 
 
@@ -44,12 +62,14 @@ impl HandlerSecurity
 		{
 			t_asset: TableAsset::new(db_connection.clone()),
 			t_security: TableSecurity::new(db_connection.clone()),
+			h_security_name_history: HandlerSecurityNameHistory::new(db_connection.clone()),
 		}
 	}
 
 
 	/**
-	* Ensures a security exists: if not found by CIK, creates asset and security rows.
+	* Ensures a security exists: if not found by CIK, creates asset and security rows. The asset name follows the current
+	* name on every sync and former names are kept in security_name_history.
 	* Hash comparison and updates are done by the caller (HandlerSecurityProfile).
 	*/
 	pub async fn synchronize(
@@ -59,10 +79,15 @@ impl HandlerSecurity
 	{
 		log_debug!("Synchronizing security..");
 
-		if let Some(_) = self.t_security.get_by_cik(&synchronize_security.cik).await?
+		if let Some(security) = self.t_security.get_by_cik(&synchronize_security.cik).await?
 		{
 			log_debug!("Security found in database. Updating it now..");
 
+			if !synchronize_security.name.is_empty()
+			{
+				self.t_asset.update_name(security.asset_id, &synchronize_security.name).await?;
+			}
+
 			self.t_security.update_row(
 				&synchronize_security.cik,
 				&synchronize_security.business_city,
@@ -70,11 +95,25 @@ impl HandlerSecurity
 				&synchronize_security.business_state,
 				&synchronize_security.business_street1,
 				&synchronize_security.business_zip,
+				&synchronize_security.mailing_city,
+				&synchronize_security.mailing_country,
+				&synchronize_security.mailing_state,
+				&synchronize_security.mailing_street1,
+				&synchronize_security.mailing_zip,
+				&synchronize_security.category,
 				&synchronize_security.description,
 				&synchronize_security.ein,
 				&synchronize_security.entity_type,
+				&synchronize_security.fiscal_year_end,
+				&synchronize_security.flags,
+				synchronize_security.insider_transaction_for_issuer_exists,
+				synchronize_security.insider_transaction_for_owner_exists,
+				&synchronize_security.investor_website,
+				&synchronize_security.owner_org,
 				&synchronize_security.phone,
 				&synchronize_security.sic,
+				&synchronize_security.sic_description,
+				&synchronize_security.state_of_incorporation,
 				&synchronize_security.website,
 			).await?;
 		}
@@ -97,16 +136,31 @@ impl HandlerSecurity
 				&synchronize_security.business_state,
 				&synchronize_security.business_street1,
 				&synchronize_security.business_zip,
+				&synchronize_security.mailing_city,
+				&synchronize_security.mailing_country,
+				&synchronize_security.mailing_state,
+				&synchronize_security.mailing_street1,
+				&synchronize_security.mailing_zip,
+				&synchronize_security.category,
 				&synchronize_security.cik,
 				&synchronize_security.description,
 				&synchronize_security.ein,
 				&synchronize_security.entity_type,
+				&synchronize_security.fiscal_year_end,
+				&synchronize_security.flags,
+				synchronize_security.insider_transaction_for_issuer_exists,
+				synchronize_security.insider_transaction_for_owner_exists,
+				&synchronize_security.investor_website,
+				&synchronize_security.owner_org,
 				&synchronize_security.phone,
 				&synchronize_security.sic,
+				&synchronize_security.sic_description,
+				&synchronize_security.state_of_incorporation,
 				&synchronize_security.website,
 			).await?;
 		}
 
+		self.h_security_name_history.synchronize(&synchronize_security.cik, &synchronize_security.former_names).await?;
 
 		Ok(())
 	}
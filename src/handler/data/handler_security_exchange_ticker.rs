@@ -1,10 +1,12 @@
+use std::collections::HashSet;
 use std::sync::Arc;
-use sqlx::Row;
+use chrono::NaiveDate;
 
 use crate::database::database_connection::DatabaseConnection;
-use crate::database::table_security_exchange_ticker::TableSecurityExchangeTicker;
+use crate::database::table_security_exchange_ticker::{ SecurityExchangeTickerRow, TableSecurityExchangeTicker };
 
-use crate::{ log_debug, log_superdebug };
+use crate::schema::SubmissionsDataExchangeTicker;
+use crate::{ log_debug, log_superdebug, log_warn };
 
 
 pub struct HandlerSecurityExchangeTicker
@@ -26,54 +28,94 @@ impl HandlerSecurityExchangeTicker
 		}
 	}
 
+	/**
+	* Reconcile the open periods of a security with the (exchange, ticker) pairs of the latest submission: a listed
+	* pair without an open period gets one, an open period whose pair is no longer listed is closed. Pairs are
+	* compared as a whole, so a ticker that moved exchange closes the old pair and opens the new one. A submission older
+	* than the latest open period is skipped, it would close that period before it started.
+	* @visibility: Public
+	* @param security_cik {&str} CIK of the security
+	* @param exchange_tickers {&[SubmissionsDataExchangeTicker]} Pairs of the latest submission
+	* @param as_of {&NaiveDate} Date the listing was observed, used as valid_from of new and valid_to of closed periods
+	*/
 	pub async fn synchronize(
 		&self,
 		security_cik: &str,
-		exchanges: &Vec<String>,
-		tickers: &Vec<String>,
+		exchange_tickers: &[SubmissionsDataExchangeTicker],
+		as_of: &NaiveDate,
 	) -> Result<(), Box<dyn std::error::Error>>
 	{
 		log_debug!("Synchronizing security_exchange_ticker..");
 
-		if tickers.len() != exchanges.len()
-		{
-			return Err("Tickers and exchanges must have the same length".into());
-		}
+		let listed: HashSet<&SubmissionsDataExchangeTicker> = exchange_tickers.iter().collect();
 
-		for (i, ticker) in tickers.iter().enumerate()
-		{
-			let result = self.t_security_exchange_ticker.find_rows(&security_cik, &exchanges[i], &ticker).await?;
+		let mut open: HashSet<SubmissionsDataExchangeTicker> = HashSet::new();
 
-			if result.len() == 0
-			{
-				self.t_security_exchange_ticker.create_row(&security_cik, &exchanges[i], &ticker).await?;
-			}
-		}
+		let mut row_with_id_to_be_closed: Vec<i64> = Vec::new();
 
-		let existing_rows = self.t_security_exchange_ticker.read_rows(&security_cik).await?;
+		let rows: Vec<SecurityExchangeTickerRow> = self.t_security_exchange_ticker.read_rows(security_cik).await?;
 
-		let mut row_with_id_to_be_deleted: Vec<i64> = Vec::new();
+		if let Some(latest_valid_from) = rows.iter().filter_map(|row| row.valid_from).max().filter(|d| d > as_of)
+		{
+			log_warn!(
+				"[DATA QUALITY] CIK {} has ticker periods open since {}, skipping its tickers as of {}",
+				security_cik,
+				latest_valid_from,
+				as_of
+			);
+
+			return Ok(());
+		}
 
-		for row in existing_rows
+		for row in rows
 		{
-			let existing_row_ticker: String = row.get("ticker");
-			let existing_row_exchange: String = row.get("exchange");
+			let existing = SubmissionsDataExchangeTicker { exchange: row.exchange, ticker: row.ticker };
 
-			log_superdebug!("Verifying row with ticker {} and exchange {}", existing_row_ticker, existing_row_exchange);
+			log_superdebug!("Verifying open row {}", existing);
 
-			if !tickers.contains(&existing_row_ticker.to_string()) || !exchanges.contains(&existing_row_exchange.to_string())
+			if listed.contains(&existing) && !open.contains(&existing)
 			{
-				row_with_id_to_be_deleted.push(row.get("id"));
+				open.insert(existing);
+			}
+			else
+			{
+				row_with_id_to_be_closed.push(row.id);
 			}
 		}
 
-		log_debug!("Deleting rows with IDs: {:?}", row_with_id_to_be_deleted);
+		log_debug!("Closing rows with IDs: {:?}", row_with_id_to_be_closed);
+
+		for id in row_with_id_to_be_closed
+		{
+			self.t_security_exchange_ticker.close_row(id, as_of).await?;
+		}
 
-		for id in row_with_id_to_be_deleted
+		for exchange_ticker in exchange_tickers.iter().filter(|e| !open.contains(*e))
 		{
-			self.t_security_exchange_ticker.delete_row(id).await?;
+			self.t_security_exchange_ticker.create_row(
+				security_cik,
+				exchange_ticker.exchange.as_deref(),
+				&exchange_ticker.ticker,
+				as_of
+			).await?;
 		}
 
 		Ok(())
 	}
+
+	/**
+	* Which CIK(s) held `ticker` on `date`. More than one row means the ticker was listed by several CIKs, e.g. on
+	* different exchanges.
+	* @visibility: Public
+	* @param ticker {&str} Ticker, e.g. "AAPL"
+	* @param date {&NaiveDate} Day to look up
+	*/
+	pub async fn find_by_ticker_on_date(
+		&self,
+		ticker: &str,
+		date: &NaiveDate,
+	) -> Result<Vec<SecurityExchangeTickerRow>, Box<dyn std::error::Error>>
+	{
+		self.t_security_exchange_ticker.find_rows_by_ticker_on_date(ticker, date).await
+	}
 }
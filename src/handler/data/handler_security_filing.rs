@@ -37,23 +37,48 @@ impl HandlerSecurityFiling
 
 		for f in filings
 		{
-			if let Some(_) = self.t_security_filing.read_row(&f.accession_number).await?
+			if let Some(row) = self.t_security_filing.read_row(&f.accession_number).await?
 			{
 				log_ultradebug!(
 					"Row with accession_number {} already exists in database",
 					f.accession_number
 				);
 
+				if row.primary_document.is_none()
+				{
+					self.t_security_filing.update_row(
+						&f.accession_number,
+						&f.act,
+						&f.file_number,
+						&f.film_number,
+						&f.items,
+						f.size,
+						f.is_xbrl,
+						f.is_inline_xbrl,
+						&f.primary_document,
+						&f.primary_doc_description,
+					).await?;
+				}
+
 				continue;
 			}
 
 			self.t_security_filing.create_row(
-				&security_cik,
+				security_cik,
 				&f.accession_number,
 				&f.form,
 				&f.filing_date,
 				&f.report_date,
 				&f.acceptance,
+				&f.act,
+				&f.file_number,
+				&f.film_number,
+				&f.items,
+				f.size,
+				f.is_xbrl,
+				f.is_inline_xbrl,
+				&f.primary_document,
+				&f.primary_doc_description,
 			).await?;
 		}
 
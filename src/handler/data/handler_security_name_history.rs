@@ -0,0 +1,84 @@
+use std::sync::Arc;
+use chrono::NaiveDate;
+
+use crate::database::database_connection::DatabaseConnection;
+use crate::database::table_security_name_history::TableSecurityNameHistory;
+use crate::schema::SubmissionsDataFormerName;
+
+use crate::{ log_debug };
+
+
+pub struct HandlerSecurityNameHistory
+{
+	t_security_name_history: TableSecurityNameHistory,
+}
+
+
+impl HandlerSecurityNameHistory
+{
+	/**
+	* @visibility: Public
+	*/
+	pub fn new(db_connection: Arc<DatabaseConnection>) -> Self
+	{
+		Self
+		{
+			t_security_name_history: TableSecurityNameHistory::new(db_connection.clone()),
+		}
+	}
+
+	/**
+	* Make the stored former names of a security match `former_names`: missing ones are inserted, ones no longer
+	* reported are deleted
+	* @visibility: Public
+	* @param security_cik {&str} CIK of the security
+	* @param former_names {&Vec<SubmissionsDataFormerName>} Former names from the latest submission
+	*/
+	pub async fn synchronize(
+		&self,
+		security_cik: &str,
+		former_names: &Vec<SubmissionsDataFormerName>,
+	) -> Result<(), Box<dyn std::error::Error>>
+	{
+		log_debug!("Synchronizing security_name_history..");
+
+		let existing_rows = self.t_security_name_history.read_rows(security_cik).await?;
+
+		let mut existing: Vec<(i64, String, Option<NaiveDate>, Option<NaiveDate>)> = existing_rows.into_iter().map(
+			|row| (row.id, row.name, row.from_date, row.to_date)
+		).collect();
+
+		for former_name in former_names
+		{
+			let position = existing.iter().position(
+				|(_, name, from, to)| *name == former_name.name && *from == former_name.from && *to == former_name.to
+			);
+
+			match position
+			{
+				Some(i) =>
+				{
+					existing.swap_remove(i);
+				},
+				None =>
+				{
+					self.t_security_name_history.create_row(
+						security_cik,
+						&former_name.name,
+						former_name.from,
+						former_name.to
+					).await?;
+				},
+			}
+		}
+
+		log_debug!("Deleting {} former names no longer reported", existing.len());
+
+		for (id, _, _, _) in existing
+		{
+			self.t_security_name_history.delete_row(id).await?;
+		}
+
+		Ok(())
+	}
+}
@@ -1,7 +1,8 @@
-pub mod handler_filing_assets;
-pub mod handler_filing_common_stock_shares_outstanding;
-pub mod handler_filing_entity_common_stock_shares_outstanding;
+pub mod handler_filing_fact;
+pub mod handler_filing_legacy;
+pub mod handler_sec_parse_quarantine;
 pub mod handler_sec_submission_file_hash;
 pub mod handler_security;
 pub mod handler_security_exchange_ticker;
 pub mod handler_security_filing;
+pub mod handler_security_name_history;
@@ -1,197 +1,363 @@
+use rust_decimal::Decimal;
+use serde::de::DeserializeSeed;
 use serde_json::Value;
+use std::collections::{ HashMap, HashSet };
+use std::io::{ BufReader, Read };
 use std::path::PathBuf;
 use zip::ZipArchive;
+use zip::read::ZipFile;
 
 use std::fs::{ File };
 
+use crate::{ log_info };
+use crate::handler::file::zip::zip_fingerprint::compute_file_names_to_fingerprints;
+
 use crate::schema::{
-	Assets,
 	Companyfacts,
-	CommonStockSharesOutstanding,
-	EntityCommonStockSharesOutstanding,
+	Fact,
+	FactConcept,
+	FileFingerprint,
+	JsonCompanyfacts,
+	JsonCompanyfactsSeed,
+	ParseError,
 };
 
 
 pub struct HandlerFileCompanyfactsZip
 {
+	path: PathBuf,
 	archive: ZipArchive<File>,
 }
 
 
 impl HandlerFileCompanyfactsZip
 {
-	fn extract_assets(&mut self, json_submission: &Value) -> Result<Vec<Assets>, Box<dyn std::error::Error>>
+	/**
+	* Parse an XBRL value exactly, without going through f64. Numbers keep their original text thanks to
+	* serde_json's arbitrary_precision, so 0.1 stays 0.1 and large share counts keep every digit.
+	* @visibility private
+	* @param raw_val {&Value} The `val` of a fact
+	*/
+	fn parse_val(raw_val: &Value) -> Option<Decimal>
 	{
-		let assets: Vec<Assets> = json_submission.get(
-			"facts"
-		).and_then(
-			|v| v.get("us-gaap")
-		).and_then(
-			|v| v.get("Assets")
-		).and_then(
-			|v| v.get("units")
-		).and_then(
-			|v| v.get("USD")
-		).and_then(
-			|v| v.as_array()
-		).filter(
-			|arr| !arr.is_empty()
-		).map(
-			|arr| {
-				arr.iter().filter_map(|item| {
-					Some(
-						Assets
-						{
-							security_filing_accession_number: item.get("accn")?.as_str()?.to_owned(),
-							end: item.get("end")?.as_str()?.to_owned(),
-							fp: item.get("fp")?.as_str()?.to_owned(),
-							fy: item.get("fy")?.as_i64()?,
-							val: item.get("val")?.as_i64()?,
-						}
-					)
-				}).collect()
-			}
-		).unwrap_or_default();
+		let raw: String = match raw_val
+		{
+			Value::Number(n) => n.to_string(),
+			Value::String(s) => s.trim().to_string(),
+			_ => return None,
+		};
 
-		Ok(assets)
+		Decimal::from_str_exact(&raw).or_else(|_| Decimal::from_scientific(&raw)).ok()
 	}
 
-	fn extract_common_stock_shares_outstanding(
-		&mut self,
-		json_submission: &Value
-	) -> Result<Vec<CommonStockSharesOutstanding>, Box<dyn std::error::Error>>
+	/**
+	* Extract the facts of the given concepts from a companyfacts JSON, walking every taxonomy and unit so IFRS filers
+	* and non-USD reporters are included
+	* Returns the facts and how many were dropped for a missing field or an unparseable value
+	* @visibility private
+	* @param json_companyfacts {&JsonCompanyfacts} Companyfacts JSON data, already reduced to the concepts we need
+	* @param fact_concepts {&[FactConcept]} Concepts to extract, matched by name or by one of their equivalents
+	*/
+	fn extract_facts(json_companyfacts: &JsonCompanyfacts, fact_concepts: &[FactConcept]) -> (Vec<Fact>, usize)
 	{
-		let common_stock_shares_outstanding: Vec<CommonStockSharesOutstanding> = json_submission.get(
-			"facts"
-		).and_then(
-			|v| v.get("us-gaap")
-		).and_then(
-			|v| v.get("CommonStockSharesOutstanding")
-		).and_then(
-			|v| v.get("units")
-		).and_then(
-			|v| v.get("shares")
-		).and_then(
-			|v| v.as_array()
-		).filter(
-			|arr| !arr.is_empty()
-		).map(
-			|arr| {
-				arr.iter().filter_map(|item| {
-					Some(
-						CommonStockSharesOutstanding
-						{
-							security_filing_accession_number: item.get("accn")?.as_str()?.to_owned(),
-							end: item.get("end")?.as_str()?.to_owned(),
-							fp: item.get("fp")?.as_str()?.to_owned(),
-							fy: item.get("fy")?.as_i64()?,
-							val: item.get("val")?.as_i64()?,
-						}
-					)
-				}).collect()
-			}
-		).unwrap_or_default();
+		let names_to_fact_concepts: HashMap<String, &FactConcept> = fact_concepts.iter().flat_map(
+			|c| c.names().into_iter().map(move |name| (name, c))
+		).collect();
 
-		Ok(common_stock_shares_outstanding)
-	}
+		let mut facts: Vec<Fact> = Vec::new();
+		let mut dropped: usize = 0;
 
-	fn extract_entity_common_stock_shares_outstanding(
-		&mut self,
-		json_submission: &Value
-	) -> Result<Vec<EntityCommonStockSharesOutstanding>, Box<dyn std::error::Error>>
-	{
-		let entity_common_stock_shares_outstanding: Vec<EntityCommonStockSharesOutstanding> = json_submission.get(
-			"facts"
-		).and_then(
-			|v| v.get("dei")
-		).and_then(
-			|v| v.get("EntityCommonStockSharesOutstanding")
-		).and_then(
-			|v| v.get("units")
-		).and_then(
-			|v| v.get("shares")
-		).and_then(
-			|v| v.as_array()
-		).filter(
-			|arr| !arr.is_empty()
-		).map(
-			|arr| {
-				arr.iter().filter_map(|item| {
+		for json_concept in &json_companyfacts.concepts
+		{
+			let name: String = format!("{}:{}", json_concept.taxonomy, json_concept.concept);
+
+			let Some(fact_concept) = names_to_fact_concepts.get(&name) else { continue };
+
+			for (unit, items) in &json_concept.units
+			{
+				if fact_concept.unit.as_ref().is_some_and(|u| u != unit)
+				{
+					continue;
+				}
+
+				let before: usize = facts.len();
+
+				facts.extend(items.iter().filter_map(|item| {
 					Some(
-						EntityCommonStockSharesOutstanding
+						Fact
 						{
-							security_filing_accession_number: item.get("accn")?.as_str()?.to_owned(),
-							end: item.get("end")?.as_str()?.to_owned(),
-							fp: item.get("fp")?.as_str()?.to_owned(),
-							fy: item.get("fy")?.as_i64()?,
-							val: item.get("val")?.as_i64()?,
+							canonical_concept: fact_concept.canonical(),
+							taxonomy: json_concept.taxonomy.clone(),
+							concept: json_concept.concept.clone(),
+							unit: unit.clone(),
+							security_filing_accession_number: item.accn.clone()?,
+							start: item.start.clone(),
+							end: item.end.clone()?,
+							fp: item.fp.clone()?,
+							fy: item.fy?,
+							val: Self::parse_val(item.val.as_ref()?)?,
+							filed: item.filed.clone(),
+							form: item.form.clone(),
+							frame: item.frame.clone(),
 						}
 					)
-				}).collect()
+				}));
+
+				dropped += items.len() - (facts.len() - before);
 			}
-		).unwrap_or_default();
+		}
 
-		Ok(entity_common_stock_shares_outstanding)
+		(facts, dropped)
 	}
 
-
 	pub fn new(path: PathBuf) -> Result<Self, Box<dyn std::error::Error>>
 	{
 		let file = File::open(&path)?;
 
 		let archive = ZipArchive::new(file)?;
 
-		Ok(Self { archive, })
+		Ok(Self { path, archive, })
+	}
+
+	/**
+	* Read the fingerprint of every file directly from the companyfacts.zip central directory (NO extraction)
+	* Returns HashMap<file_name, FileFingerprint>
+	* @param strict {bool} Also decompress every file to compute its SHA-256
+	*/
+	pub fn compute_file_names_to_fingerprints(
+		&mut self,
+		strict: bool,
+	) -> Result<HashMap<String, FileFingerprint>, Box<dyn std::error::Error>>
+	{
+		log_info!("Reading {} file names and fingerprints..", self.path.display());
+
+		compute_file_names_to_fingerprints(&mut self.archive, strict)
 	}
 
 	/**
-	* Load JSON from a file inside submissions.zip
+	* Load JSON from a file inside companyfacts.zip
 	*/
 	pub fn load_json_from_file(&mut self, file_name: &str,) -> Result<Value, Box<dyn std::error::Error>>
+	{
+		let value: Value = serde_json::from_reader(self.open_file(file_name)?)?;
+
+		Ok(value)
+	}
+
+	/**
+	* Buffered reader over a file inside companyfacts.zip. serde_json reads byte by byte, so it must be buffered.
+	*/
+	fn open_file(&mut self, file_name: &str) -> Result<BufReader<ZipFile<'_>>, Box<dyn std::error::Error>>
 	{
 		// ZipArchive requires mutable access because reading advances internal cursor
-		let mut zipped_file = self.archive.by_name(file_name)?;
+		let zipped_file = self.archive.by_name(file_name)?;
 
 		if zipped_file.is_dir()
 		{
 			return Err(format!("{} is a directory", file_name).into());
 		}
 
-		// Deserialize directly from the file reader (streaming)
-		let value: Value = serde_json::from_reader(&mut zipped_file)?;
-
-		Ok(value)
+		Ok(BufReader::new(zipped_file))
 	}
 
-	pub fn extract_data(&mut self, file_name: &str) -> Result<Companyfacts, Box<dyn std::error::Error>>
+	/**
+	* Parse companyfacts from a companyfacts JSON, wherever it came from (companyfacts.zip or the SEC API).
+	* The JSON is streamed and only the concepts we extract are deserialized, the rest is skipped.
+	* The filing_assets, filing_common_stock_shares_outstanding and filing_entity_common_stock_shares_outstanding
+	* tables are always populated. `fact_concepts` only decides what goes into filing_fact.
+	* @param reader {R} Companyfacts JSON
+	* @param fact_concepts {&[FactConcept]} Concepts to extract for filing_fact
+	*/
+	pub fn parse_companyfacts<R: Read>(
+		reader: R,
+		fact_concepts: &[FactConcept]
+	) -> Result<Companyfacts, Box<dyn std::error::Error>>
 	{
-		let json_submission: Value = self.load_json_from_file(file_name)?;
+		let legacy_concepts: [FactConcept; 3] = [
+			FactConcept::new("us-gaap", "Assets", Some("USD")),
+			FactConcept::new("us-gaap", "CommonStockSharesOutstanding", Some("shares")),
+			FactConcept::new("dei", "EntityCommonStockSharesOutstanding", Some("shares")),
+		];
 
-		let assets: Vec<Assets> = self.extract_assets(&json_submission)?;
+		let names: HashSet<String> = fact_concepts.iter().chain(legacy_concepts.iter()).flat_map(|c| c.names()).collect();
 
-		let common_stock_shares_outstanding: Vec<
-			CommonStockSharesOutstanding
-		> = self.extract_common_stock_shares_outstanding(
-			&json_submission
-		)?;
+		let mut deserializer = serde_json::Deserializer::from_reader(reader);
 
-		let entity_common_stock_shares_outstanding: Vec<
-			EntityCommonStockSharesOutstanding
-		> = self.extract_entity_common_stock_shares_outstanding(
-			&json_submission
-		)?;
+		let json_companyfacts: JsonCompanyfacts = JsonCompanyfactsSeed { names: &names }.deserialize(&mut deserializer)?;
+
+		deserializer.end()?;
+
+		let json_companyfacts = &json_companyfacts;
+
+		let (extracted, mut dropped_facts) = Self::extract_facts(json_companyfacts, fact_concepts);
+
+		// filing_fact also needs the filing date and form, the legacy tables keep facts without them
+		let (facts, unfiled): (Vec<Fact>, Vec<Fact>) = extracted.into_iter().partition(
+			|f| f.filed.is_some() && f.form.is_some()
+		);
+
+		dropped_facts += unfiled.len();
+
+		// The legacy tables keep their original US GAAP concept and unit. Reuse the facts above when the configuration
+		// already covers one, so its drops are not counted twice.
+		let [assets, common_stock_shares_outstanding, entity_common_stock_shares_outstanding] = legacy_concepts.map(
+			|legacy_concept|
+			{
+				if fact_concepts.iter().any(|c| c.covers(&legacy_concept))
+				{
+					return facts.iter().chain(unfiled.iter()).filter(
+						|f| legacy_concept.canonical() == format!("{}:{}", f.taxonomy, f.concept) &&
+							legacy_concept.unit.as_ref() == Some(&f.unit)
+					).cloned().collect::<Vec<Fact>>();
+				}
+
+				let (legacy_facts, dropped) = Self::extract_facts(json_companyfacts, std::slice::from_ref(&legacy_concept));
+
+				dropped_facts += dropped;
+
+				legacy_facts
+			}
+		);
+
+		let cik: String = json_companyfacts.cik.clone().unwrap_or_default();
 
 		Ok(
 			Companyfacts {
+				cik,
+				facts,
 				assets,
 				common_stock_shares_outstanding,
 				entity_common_stock_shares_outstanding,
+				dropped_facts,
 			}
 		)
 	}
 
-	pub fn file_exists(&mut self, file_name: &str) -> bool
+	pub fn extract_data(
+		&mut self,
+		file_name: &str,
+		fact_concepts: &[FactConcept]
+	) -> Result<Companyfacts, ParseError>
+	{
+		let reader = self.open_file(file_name).map_err(|e| ParseError::from_error(file_name, e.as_ref()))?;
+
+		Self::parse_companyfacts(reader, fact_concepts).map_err(|e| ParseError::from_error(file_name, e.as_ref()))
+	}
+}
+
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	use std::str::FromStr;
+
+
+	fn parse_val(raw_val: &str) -> Option<Decimal>
+	{
+		HandlerFileCompanyfactsZip::parse_val(&serde_json::from_str(raw_val).unwrap())
+	}
+
+	#[test]
+	fn parse_val_keeps_every_digit()
+	{
+		for (raw_val, expected) in [
+			("0.1", "0.1"),
+			("12.50", "12.50"),
+			("-3", "-3"),
+			("123456789012345678901", "123456789012345678901"),
+			("1234567890123.1234567891", "1234567890123.1234567891"),
+			("1.5e3", "1500"),
+			("2.5E-2", "0.025"),
+			("\" 42 \"", "42"),
+			("\"0.3\"", "0.3"),
+		]
+		{
+			assert_eq!(parse_val(raw_val), Some(Decimal::from_str(expected).unwrap()), "{}", raw_val);
+		}
+	}
+
+	#[test]
+	fn parse_val_drops_what_is_not_a_decimal()
 	{
-		self.archive.by_name(file_name).is_ok()
+		for raw_val in ["null", "true", "[1]", "{}", "\"\"", "\"n/a\"", "1e40"]
+		{
+			assert_eq!(parse_val(raw_val), None, "{}", raw_val);
+		}
+	}
+
+	#[test]
+	fn facts_without_filed_or_form_are_kept_for_the_legacy_tables_only()
+	{
+		let json: &str = r#"{
+			"cik": 1,
+			"facts": {
+				"us-gaap": {
+					"Assets": {
+						"units": {
+							"USD": [
+								{ "accn": "0000000001-24-000001", "end": "2023-12-31", "fy": 2023, "fp": "FY", "val": 100 },
+								{
+									"accn": "0000000001-24-000002", "end": "2024-03-31", "fy": 2024, "fp": "Q1", "val": 200,
+									"filed": "2024-05-01", "form": "10-Q"
+								}
+							]
+						}
+					}
+				}
+			}
+		}"#;
+
+		let companyfacts: Companyfacts = HandlerFileCompanyfactsZip::parse_companyfacts(
+			json.as_bytes(),
+			&[FactConcept::new("us-gaap", "Assets", None)]
+		).unwrap();
+
+		assert_eq!(companyfacts.facts.len(), 1);
+		assert_eq!(companyfacts.facts[0].security_filing_accession_number, "0000000001-24-000002");
+		assert_eq!(companyfacts.dropped_facts, 1);
+
+		assert_eq!(companyfacts.assets.len(), 2);
+		assert_eq!(companyfacts.assets.iter().filter(|f| f.filed.is_none() && f.form.is_none()).count(), 1);
+	}
+
+	#[test]
+	fn entries_of_the_wrong_shape_are_dropped_instead_of_failing_the_file()
+	{
+		let json: &str = r#"{
+			"cik": 1,
+			"facts": {
+				"us-gaap": {
+					"Assets": {
+						"units": {
+							"USD": [
+								{
+									"accn": "0000000001-24-000001", "end": "2023-12-31", "fy": "2023", "fp": "FY", "val": 100,
+									"filed": "2024-02-01", "form": "10-K"
+								},
+								{
+									"accn": 1, "end": "2023-12-31", "fy": 2023, "fp": "FY", "val": 100,
+									"filed": "2024-02-01", "form": "10-K"
+								},
+								42,
+								{
+									"accn": "0000000001-24-000002", "end": "2024-03-31", "fy": 2024, "fp": "Q1", "val": 200,
+									"filed": "2024-05-01", "form": "10-Q", "frame": null
+								}
+							]
+						}
+					}
+				}
+			}
+		}"#;
+
+		let companyfacts: Companyfacts = HandlerFileCompanyfactsZip::parse_companyfacts(
+			json.as_bytes(),
+			&[FactConcept::new("us-gaap", "Assets", None)]
+		).unwrap();
+
+		assert_eq!(companyfacts.facts.len(), 1);
+		assert_eq!(companyfacts.facts[0].security_filing_accession_number, "0000000001-24-000002");
+		assert_eq!(companyfacts.dropped_facts, 3);
 	}
 }
@@ -1,16 +1,26 @@
-use serde_json::Value;
+use serde::de::DeserializeOwned;
 use std::collections::HashMap;
-use std::io::Read;
+use std::io::BufReader;
 use std::path::PathBuf;
 use zip::ZipArchive;
 
 use chrono::{ DateTime, Utc, NaiveDate, NaiveDateTime };
-use sha2::{ Digest, Sha256 };
 use std::fs::{ File };
 
 
-use crate::{ log_info };
-use crate::schema::{ SubmissionsData, SubmissionsDataFilings };
+use crate::{ log_info, log_warn };
+use crate::handler::file::zip::zip_fingerprint::compute_file_names_to_fingerprints;
+use crate::schema::{
+	FileFingerprint,
+	JsonSubmissions,
+	JsonSubmissionsFilingsArrays,
+	JsonSubmissionsFormerName,
+	ParseError,
+	SubmissionsData,
+	SubmissionsDataExchangeTicker,
+	SubmissionsDataFilings,
+	SubmissionsDataFormerName,
+};
 
 
 pub struct HandlerFileSubmissionsZip
@@ -36,9 +46,9 @@ impl HandlerFileSubmissionsZip
 	* @visibility private
 	* @param raw_acceptance {&str} Raw acceptance datetime string
 	*/
-	fn parse_acceptance_datetime(&self, raw_acceptance: &str) -> NaiveDateTime
+	fn parse_acceptance_datetime(raw_acceptance: &str) -> Option<NaiveDateTime>
 	{
-		DateTime::parse_from_rfc3339(raw_acceptance).ok().map(|dt| dt.with_timezone(&Utc).naive_utc()).unwrap()
+		DateTime::parse_from_rfc3339(raw_acceptance).ok().map(|dt| dt.with_timezone(&Utc).naive_utc())
 	}
 
 	/**
@@ -46,87 +56,133 @@ impl HandlerFileSubmissionsZip
 	* @visibility private
 	* @param raw_date {&str} Raw date string
 	*/
-	fn parse_date(&self, raw_date: &str) -> Option<NaiveDate>
+	fn parse_date(raw_date: &str) -> Option<NaiveDate>
 	{
 		NaiveDate::parse_from_str(raw_date, "%Y-%m-%d").ok()
 	}
 
 	/**
-	* Extract exchanges from a JSON submission file inside submissions.zip
+	* Former names of a submission. Entries without a name are skipped, `from`/`to` keep only their date part.
 	* @visibility private
-	* @param json_submission {&Value} Submission JSON data
+	* @param former_names {&[JsonSubmissionsFormerName]} `formerNames` of a submission
 	*/
-	fn extract_submission_data_exchanges(
-		&mut self,
-		json_submission: &Value
-	) -> Result<Vec<String>, Box<dyn std::error::Error>>
+	fn extract_submission_data_former_names(former_names: &[JsonSubmissionsFormerName]) -> Vec<SubmissionsDataFormerName>
 	{
-		let exchanges: Vec<String> = json_submission.get("exchanges").and_then(|v| v.as_array()).filter(
-			|arr| !arr.is_empty()
-		).map(
-			|arr|
+		let date = |v: &Option<String>|
+		{
+			v.as_deref().and_then(|raw| raw.get(..10)).and_then(Self::parse_date)
+		};
+
+		former_names.iter().filter_map(
+			|former_name| former_name.name.as_ref().map(
+				|name| SubmissionsDataFormerName
+				{
+					name: name.clone(),
+					from: date(&former_name.from),
+					to: date(&former_name.to),
+				}
+			)
+		).collect()
+	}
+
+	/**
+	* Pair `tickers[i]` with `exchanges[i]`. Data-quality problems are logged and tolerated: arrays of different
+	* lengths pair what they can, a null or empty ticker is skipped, a null or empty exchange becomes None and
+	* duplicate pairs are kept once.
+	* @visibility private
+	* @param cik {&str} CIK of the submission, for the warnings
+	* @param tickers {&[Option<String>]} `tickers` of a submission
+	* @param exchanges {&[Option<String>]} `exchanges` of a submission
+	*/
+	fn extract_submission_data_exchange_tickers(
+		cik: &str,
+		tickers: &[Option<String>],
+		exchanges: &[Option<String>],
+	) -> Vec<SubmissionsDataExchangeTicker>
+	{
+		if tickers.len() != exchanges.len()
+		{
+			log_warn!(
+				"[DATA QUALITY] CIK {} has {} tickers but {} exchanges, tickers without an exchange are stored with a NULL exchange",
+				cik,
+				tickers.len(),
+				exchanges.len()
+			);
+		}
+
+		let mut exchange_tickers: Vec<SubmissionsDataExchangeTicker> = Vec::with_capacity(tickers.len());
+
+		for (i, ticker) in tickers.iter().enumerate()
+		{
+			let exchange: Option<String> = exchanges.get(i).cloned().flatten().filter(|e| !e.is_empty());
+
+			let ticker: String = match ticker.as_deref().filter(|t| !t.is_empty())
 			{
-				arr.iter().map(
-					|v|
-					{
-						if let Some(s) = v.as_str()
-						{
-							s.to_string()
-						}
-						else if v.is_null()
-						{
-							"null".to_string()
-						}
-						else
-						{
-							// Optional: decide what to do with non-string, non-null values
-							v.to_string()
-						}
-					}
-				).collect()
+				Some(ticker) => ticker.to_string(),
+				None =>
+				{
+					log_warn!(
+						"[DATA QUALITY] CIK {} has no ticker at tickers[{}] (exchange {}), skipping it",
+						cik,
+						i,
+						exchange.as_deref().unwrap_or("NULL")
+					);
+
+					continue;
+				},
+			};
+
+			let exchange_ticker = SubmissionsDataExchangeTicker { exchange, ticker };
+
+			if exchange_tickers.contains(&exchange_ticker)
+			{
+				log_warn!("[DATA QUALITY] CIK {} lists {} more than once", cik, exchange_ticker);
+
+				continue;
 			}
-		).unwrap_or_default();
 
-		Ok(exchanges)
+			exchange_tickers.push(exchange_ticker);
+		}
+
+		exchange_tickers
 	}
 
 	/**
-	* Extract filings from a JSON submission file inside submissions.zip
+	* Extract filings from the column arrays of `filings.recent` or of an older filings file
 	* @visibility private
-	* @param json_submission {&Value} Submission JSON data
+	* @param json_filings {&JsonSubmissionsFilingsArrays} accessionNumber, filingDate, .. arrays
+	* @param field_prefix {&str} Where the arrays are, for error reporting, e.g. "filings.recent"
 	*/
-	fn extract_submission_data_filings(
-		&mut self,
-		json_submission: &Value
-	) -> Result<Vec<SubmissionsDataFilings>, Box<dyn std::error::Error>>
+	fn extract_submission_data_filings_arrays(
+		json_filings: &JsonSubmissionsFilingsArrays,
+		field_prefix: &str,
+	) -> Result<Vec<SubmissionsDataFilings>, ParseError>
 	{
-		let recent_filings = json_submission.get("filings").and_then(|v| v.get("recent"));
+		let field = |name: &str| format!("{}.{}", field_prefix, name);
 
-		let get_vec = |v: Option<&Value>| -> Vec<String>
+		let get = |column: &Vec<Option<String>>, i: usize| -> Option<String>
 		{
-			v.and_then(|v| v.as_array()).map(
-				|arr| {
-					arr.iter().filter_map(|v| v.as_str().map(String::from)).collect()
-				}
-			).unwrap_or_default()
+			column.get(i).cloned().flatten()
 		};
 
-		let accession_number = get_vec(recent_filings.and_then(|v| v.get("accessionNumber")));
-		let filing_dates = get_vec(recent_filings.and_then(|v| v.get("filingDate")));
-		let report_dates = get_vec(recent_filings.and_then(|v| v.get("reportDate")));
-		let acceptance = get_vec(recent_filings.and_then(|v| v.get("acceptanceDateTime")));
+		let get_int = |column: &Vec<Option<i64>>, i: usize| -> Option<i64>
+		{
+			column.get(i).copied().flatten()
+		};
 
-		let filings_len = accession_number.len();
+		let filings_len = json_filings.accession_number.len();
 
 		let mut filings = Vec::with_capacity(filings_len);
 
 		for i in 0..filings_len
 		{
-			let filing_date = filing_dates.get(i).and_then(|s| self.parse_date(s)).ok_or(
-				"Missing or invalid filing_date"
+			let raw_filing_date: Option<String> = get(&json_filings.filing_date, i);
+
+			let filing_date = raw_filing_date.as_deref().and_then(Self::parse_date).ok_or_else(
+				|| ParseError::new(&field("filingDate"), Some(i), raw_filing_date.as_deref(), "missing or not a YYYY-MM-DD date")
 			)?;
 
-			let report_date = report_dates.get(i).and_then(
+			let report_date = get(&json_filings.report_date, i).and_then(
 				|s|
 				{
 					if s.is_empty()
@@ -135,239 +191,286 @@ impl HandlerFileSubmissionsZip
 					}
 					else
 					{
-						self.parse_date(s)
+						Self::parse_date(&s)
 					}
 				}
 			);
 
-			let acceptance_dt = acceptance.get(i).ok_or("Missing acceptanceDateTime")?;
+			let raw_acceptance_dt: Option<String> = get(&json_filings.acceptance_date_time, i);
 
-			let acceptance_dt = self.parse_acceptance_datetime(acceptance_dt);
+			let acceptance_dt = raw_acceptance_dt.as_deref().and_then(Self::parse_acceptance_datetime).ok_or_else(
+				|| ParseError::new(
+					&field("acceptanceDateTime"),
+					Some(i),
+					raw_acceptance_dt.as_deref(),
+					"missing or not an RFC 3339 datetime"
+				)
+			)?;
 
 			filings.push(
 				SubmissionsDataFilings
 				{
-					accession_number: accession_number.get(i).cloned().unwrap_or_default(),
+					accession_number: get(&json_filings.accession_number, i).unwrap_or_default(),
 					filing_date,
 					report_date,
-					form: get_vec(recent_filings.and_then(|v| v.get("form"))).get(i).cloned().unwrap_or_default(),
-					acceptance: acceptance_dt
+					form: get(&json_filings.form, i).unwrap_or_default(),
+					acceptance: acceptance_dt,
+					act: get(&json_filings.act, i).unwrap_or_default(),
+					file_number: get(&json_filings.file_number, i).unwrap_or_default(),
+					film_number: get(&json_filings.film_number, i).unwrap_or_default(),
+					items: get(&json_filings.items, i).unwrap_or_default(),
+					size: get_int(&json_filings.size, i),
+					is_xbrl: get_int(&json_filings.is_xbrl, i).unwrap_or(0) != 0,
+					is_inline_xbrl: get_int(&json_filings.is_inline_xbrl, i).unwrap_or(0) != 0,
+					primary_document: get(&json_filings.primary_document, i).unwrap_or_default(),
+					primary_doc_description: get(&json_filings.primary_doc_description, i).unwrap_or_default(),
 				}
 			);
 		}
 
-		let older_filings: Vec<String> = json_submission.get("filings").and_then(
-			|v| v.get("files")
-		).and_then(
-			|v| v.as_array()
-		).map(
-			|arr|
-			{
-				arr.iter().filter_map(|f| f.get("name").and_then(|n| n.as_str()).map(String::from)).collect()
-			}
-		).unwrap_or_default();
-
-		for f in older_filings
-		{
-			let json = self.load_json_from_file(&f)?;
-
-			let accession_number = get_vec(json.get("accessionNumber"));
-			let filing_dates = get_vec(json.get("filingDate"));
-			let report_dates = get_vec(json.get("reportDate"));
-			let acceptance = get_vec(json.get("acceptanceDateTime"));
-
-			let filings_len = accession_number.len();
-
-			// Extend the filings vector to avoid reallocations
-			filings.reserve(filings_len);
-
-			for i in 0..filings_len
-			{
-				let filing_date = filing_dates.get(i).and_then(|s| self.parse_date(s)).ok_or(
-					"Missing or invalid filing_date"
-				)?;
-
-				let report_date = report_dates.get(i).and_then(
-					|s|
-					{
-						if s.is_empty()
-						{
-							None
-						}
-						else
-						{
-							self.parse_date(s)
-						}
-					}
-				);
-
-				let acceptance_dt = acceptance.get(i).ok_or("Missing acceptanceDateTime")?;
+		Ok(filings)
+	}
 
-				let acceptance_dt = self.parse_acceptance_datetime(acceptance_dt);
+	/**
+	* Extract filings from a JSON submission file and its older filings files
+	* @visibility private
+	* @param json_submission {&JsonSubmissions} Submission JSON data
+	* @param json_older_filings {&[JsonSubmissionsFilingsArrays]} Contents of the files listed in `filings.files`
+	*/
+	fn extract_submission_data_filings(
+		json_submission: &JsonSubmissions,
+		json_older_filings: &[JsonSubmissionsFilingsArrays]
+	) -> Result<Vec<SubmissionsDataFilings>, ParseError>
+	{
+		let mut filings: Vec<SubmissionsDataFilings> = Self::extract_submission_data_filings_arrays(
+			&json_submission.filings.recent,
+			"filings.recent"
+		)?;
 
-				filings.push(
-					SubmissionsDataFilings
-					{
-						accession_number: accession_number.get(i).cloned().unwrap_or_default(),
-						filing_date,
-						report_date,
-						form: get_vec(json.get("form")).get(i).cloned().unwrap_or_default(),
-						acceptance: acceptance_dt,
-					}
-				);
-			}
+		for (file_name, json) in Self::older_filings_file_names(json_submission).iter().zip(json_older_filings)
+		{
+			filings.extend(Self::extract_submission_data_filings_arrays(json, file_name)?);
 		}
 
 		Ok(filings)
 	}
 
+
 	/**
-	* Extract tickers from a JSON submission file inside submissions.zip
-	* @visibility private
-	* @param json_submission {&Value} Submission JSON data
+	* Read the fingerprint of every file directly from the submissions.zip central directory (NO extraction)
+	* Returns HashMap<file_name, FileFingerprint>
+	* @param strict {bool} Also decompress every file to compute its SHA-256
 	*/
-	fn extract_submission_data_tickers(
+	pub fn compute_file_names_to_fingerprints(
 		&mut self,
-		json_submission: &Value
-	) -> Result<Vec<String>, Box<dyn std::error::Error>>
+		strict: bool,
+	) -> Result<HashMap<String, FileFingerprint>, Box<dyn std::error::Error>>
 	{
-		let tickers: Vec<String> = json_submission.get("tickers").and_then(|v| v.as_array()).filter(
-			|arr| !arr.is_empty()
-		).map(
-			|arr|
-			{
-				arr.iter().map(
-					|v|
-					{
-						if let Some(s) = v.as_str()
-						{
-							s.to_string()
-						}
-						else if v.is_null()
-						{
-							"null".to_string()
-						}
-						else
-						{
-							// Optional: decide what to do with non-string, non-null values
-							v.to_string()
-						}
-					}
-				).collect()
-			}
-		).unwrap_or_default();
+		log_info!("Reading {} file names and fingerprints..", self.path.display());
 
-		Ok(tickers)
+		compute_file_names_to_fingerprints(&mut self.archive, strict)
 	}
 
-
 	/**
-	* Read all files directly from submissions.zip (NO extraction)
-	* Returns HashMap<file_name, sha256_hash>
+	* Date a file inside submissions.zip was last modified, read from the zip central directory (NO extraction).
+	* Returns None when the entry carries the 1980-01-01 DOS epoch zip writers use when no time was set.
+	* @visibility public
+	* @param file_name {&str} The name of the JSON file inside submissions.zip
 	*/
-	pub fn compute_file_names_to_hashes(&mut self,) -> Result<HashMap<String, String>, Box<dyn std::error::Error>>
+	pub fn file_modified_date(&mut self, file_name: &str) -> Result<Option<NaiveDate>, Box<dyn std::error::Error>>
 	{
-		log_info!("Reading {} file names and computing file hashes..", self.path.display());
-
-		let mut results: HashMap<String, String> = HashMap::new();
-		let mut buffer: [u8; 8192] = [0u8; 8192];
+		let last_modified = self.archive.by_name(file_name)?.last_modified();
 
-		for i in 0..self.archive.len()
+		if last_modified.year() <= 1980 && last_modified.month() == 1 && last_modified.day() == 1
 		{
-			let mut zipped_file = self.archive.by_index(i)?;
-
-			if zipped_file.is_dir()
-			{
-				continue;
-			}
-
-			let name = zipped_file.name().to_string();
-			let mut hasher = Sha256::new();
-
-			loop
-			{
-				let bytes_read = zipped_file.read(&mut buffer)?;
-
-				if bytes_read == 0
-				{
-					break;
-				}
-
-				hasher.update(&buffer[..bytes_read]);
-			}
-
-			let hash = format!("{:x}", hasher.finalize());
-
-			results.insert(name, hash); // insert into HashMap
+			return Ok(None);
 		}
 
-		Ok(results)
+		Ok(
+			NaiveDate::from_ymd_opt(
+				last_modified.year() as i32,
+				last_modified.month() as u32,
+				last_modified.day() as u32
+			)
+		)
 	}
 
 	/**
-	* Load JSON from a file inside submissions.zip
+	* Deserialize a file inside submissions.zip straight from the zip reader into `T`, skipping every field `T` does
+	* not model
 	*/
-	pub fn load_json_from_file(&mut self, file_name: &str,) -> Result<Value, Box<dyn std::error::Error>>
+	pub fn load_json_from_file<T: DeserializeOwned>(&mut self, file_name: &str,) -> Result<T, Box<dyn std::error::Error>>
 	{
 		// ZipArchive requires mutable access because reading advances internal cursor
-		let mut zipped_file = self.archive.by_name(file_name)?;
+		let zipped_file = self.archive.by_name(file_name)?;
 
 		if zipped_file.is_dir()
 		{
 			return Err(format!("{} is a directory", file_name).into());
 		}
 
-		// Deserialize directly from the file reader (streaming)
-		let value: Value = serde_json::from_reader(&mut zipped_file)?;
+		// Deserialize directly from the file reader (streaming). serde_json reads byte by byte, so buffer it.
+		let value: T = serde_json::from_reader(BufReader::new(zipped_file))?;
 
 		Ok(value)
 	}
 
 	/**
-	* Extract submissions data from a JSON submission file inside submissions.zip
+	* Names of the older filings files listed in `filings.files` of a submission
 	* @visibility public
-	* @param file_name {&str} The name of the JSON file inside submissions.zip
+	* @param json_submission {&JsonSubmissions} Submission JSON data
 	*/
-	pub fn extract_submissions_data(
-		&mut self,
-		file_name: &str
-	) -> Result<SubmissionsData, Box<dyn std::error::Error>>
+	pub fn older_filings_file_names(json_submission: &JsonSubmissions) -> Vec<String>
 	{
-		let json_submission: Value = self.load_json_from_file(file_name)?;
+		json_submission.filings.files.iter().map(|f| f.name.clone()).collect()
+	}
 
-		let tickers = self.extract_submission_data_tickers(&json_submission)?;
+	/**
+	* Parse submissions data from a submission JSON, wherever it came from (submissions.zip or the SEC API)
+	* @visibility public
+	* @param json_submission {&JsonSubmissions} Submission JSON data
+	* @param json_older_filings {&[JsonSubmissionsFilingsArrays]} Contents of the files listed in `filings.files`
+	*/
+	pub fn parse_submissions_data(
+		json_submission: &JsonSubmissions,
+		json_older_filings: &[JsonSubmissionsFilingsArrays]
+	) -> Result<SubmissionsData, ParseError>
+	{
+		let exchange_tickers = Self::extract_submission_data_exchange_tickers(
+			json_submission.cik.as_deref().unwrap_or_default(),
+			&json_submission.tickers,
+			&json_submission.exchanges
+		);
 
-		let exchanges = self.extract_submission_data_exchanges(&json_submission)?;
+		let filings = Self::extract_submission_data_filings(json_submission, json_older_filings)?;
 
-		let filings = self.extract_submission_data_filings(&json_submission)?;
+		let former_names = Self::extract_submission_data_former_names(&json_submission.former_names);
 
-		let get_str = |v: Option<&Value>|
+		let get_str = |v: &Option<String>|
 		{
-			v.and_then(|v| v.as_str()).unwrap_or("").to_string()
+			v.clone().unwrap_or_default()
 		};
 
-	 	let business = json_submission.get("addresses").and_then(|a| a.get("business"));
+		let business = &json_submission.addresses.business;
+
+		let mailing = &json_submission.addresses.mailing;
 
 		Ok(
 			SubmissionsData
 			{
-				business_street1: get_str(business.and_then(|b| b.get("street1"))),
-				business_city: get_str(business.and_then(|b| b.get("city"))),
-				business_state: get_str(business.and_then(|b| b.get("stateOrCountry"))),
-				business_country: get_str(business.and_then(|b| b.get("country"))),
-				business_zip: get_str(business.and_then(|b| b.get("zipCode"))),
-				cik: get_str(json_submission.get("cik")),
-				description: get_str(json_submission.get("description")),
-				ein: get_str(json_submission.get("ein")),
-				entity_type: get_str(json_submission.get("entityType")),
-				phone: get_str(json_submission.get("phone")),
-				name: get_str(json_submission.get("name")),
-				sic: get_str(json_submission.get("sic")),
-				website: get_str(json_submission.get("website")),
-				tickers,
-				exchanges,
+				business_street1: get_str(&business.street1),
+				business_city: get_str(&business.city),
+				business_state: get_str(&business.state_or_country),
+				business_country: get_str(&business.country),
+				business_zip: get_str(&business.zip_code),
+				mailing_street1: get_str(&mailing.street1),
+				mailing_city: get_str(&mailing.city),
+				mailing_state: get_str(&mailing.state_or_country),
+				mailing_country: get_str(&mailing.country),
+				mailing_zip: get_str(&mailing.zip_code),
+				category: get_str(&json_submission.category),
+				cik: get_str(&json_submission.cik),
+				description: get_str(&json_submission.description),
+				ein: get_str(&json_submission.ein),
+				entity_type: get_str(&json_submission.entity_type),
+				fiscal_year_end: get_str(&json_submission.fiscal_year_end),
+				flags: get_str(&json_submission.flags),
+				insider_transaction_for_issuer_exists: json_submission.insider_transaction_for_issuer_exists.unwrap_or(false),
+				insider_transaction_for_owner_exists: json_submission.insider_transaction_for_owner_exists.unwrap_or(false),
+				investor_website: get_str(&json_submission.investor_website),
+				phone: get_str(&json_submission.phone),
+				name: get_str(&json_submission.name),
+				owner_org: get_str(&json_submission.owner_org),
+				sic: get_str(&json_submission.sic),
+				sic_description: get_str(&json_submission.sic_description),
+				state_of_incorporation: get_str(&json_submission.state_of_incorporation),
+				website: get_str(&json_submission.website),
+				exchange_tickers,
+				former_names,
 				filings,
 			}
 		)
 	}
+
+	/**
+	* Extract submissions data from a JSON submission file inside submissions.zip
+	* @visibility public
+	* @param file_name {&str} The name of the JSON file inside submissions.zip
+	*/
+	pub fn extract_submissions_data(
+		&mut self,
+		file_name: &str
+	) -> Result<SubmissionsData, ParseError>
+	{
+		let json_submission: JsonSubmissions = self.load_json_from_file(file_name).map_err(
+			|e| ParseError::from_error(file_name, e.as_ref())
+		)?;
+
+		let mut json_older_filings: Vec<JsonSubmissionsFilingsArrays> = Vec::new();
+
+		for f in Self::older_filings_file_names(&json_submission)
+		{
+			json_older_filings.push(self.load_json_from_file(&f).map_err(|e| ParseError::from_error(&f, e.as_ref()))?);
+		}
+
+		Self::parse_submissions_data(&json_submission, &json_older_filings).map_err(|e| e.with_file_name(file_name))
+	}
+}
+
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+
+
+	fn strings(values: &[Option<&str>]) -> Vec<Option<String>>
+	{
+		values.iter().map(|v| v.map(String::from)).collect()
+	}
+
+	fn exchange_ticker(exchange: Option<&str>, ticker: &str) -> SubmissionsDataExchangeTicker
+	{
+		SubmissionsDataExchangeTicker { exchange: exchange.map(String::from), ticker: ticker.to_string() }
+	}
+
+	#[test]
+	fn exchange_tickers_are_paired_by_index()
+	{
+		let exchange_tickers = HandlerFileSubmissionsZip::extract_submission_data_exchange_tickers(
+			"1",
+			&strings(&[Some("CO"), Some("CO-PA"), Some("COB")]),
+			&strings(&[Some("NYSE"), Some("NYSE"), Some("OTC")])
+		);
+
+		assert_eq!(
+			exchange_tickers,
+			vec![
+				exchange_ticker(Some("NYSE"), "CO"),
+				exchange_ticker(Some("NYSE"), "CO-PA"),
+				exchange_ticker(Some("OTC"), "COB"),
+			]
+		);
+	}
+
+	#[test]
+	fn exchange_tickers_tolerate_bad_data()
+	{
+		// One exchange short, a null and an empty exchange, a null and an empty ticker and a duplicate pair
+		let exchange_tickers = HandlerFileSubmissionsZip::extract_submission_data_exchange_tickers(
+			"1",
+			&strings(&[Some("CO"), None, Some(""), Some("COB"), Some("COC"), Some("CO"), Some("COD")]),
+			&strings(&[Some("NYSE"), Some("NYSE"), Some("NYSE"), None, Some(""), Some("NYSE")])
+		);
+
+		assert_eq!(
+			exchange_tickers,
+			vec![
+				exchange_ticker(Some("NYSE"), "CO"),
+				exchange_ticker(None, "COB"),
+				exchange_ticker(None, "COC"),
+				exchange_ticker(None, "COD"),
+			]
+		);
+	}
 }
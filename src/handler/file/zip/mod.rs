@@ -1,5 +1,6 @@
 pub mod handler_file_companyfacts_zip;
 pub mod handler_file_submissions_zip;
+pub mod zip_fingerprint;
 
 pub use handler_file_companyfacts_zip::HandlerFileCompanyfactsZip;
 pub use handler_file_submissions_zip::HandlerFileSubmissionsZip;
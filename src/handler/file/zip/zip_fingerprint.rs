@@ -0,0 +1,110 @@
+use std::collections::{ BTreeMap, HashMap };
+use std::fs::File;
+use std::io::Read;
+use zip::ZipArchive;
+
+use sha2::{ Digest, Sha256 };
+
+use crate::schema::FileFingerprint;
+
+
+const CIK_DIGITS: usize = 10;
+
+
+/**
+* Read the fingerprint of every file from a zip central directory (NO extraction). Only in strict mode is each file
+* decompressed to compute its SHA-256 as well.
+* Returns HashMap<file_name, FileFingerprint>
+* @param archive {&mut ZipArchive<File>} Archive to fingerprint
+* @param strict {bool} Also compute the SHA-256 of every file
+*/
+pub fn compute_file_names_to_fingerprints(
+	archive: &mut ZipArchive<File>,
+	strict: bool,
+) -> Result<HashMap<String, FileFingerprint>, Box<dyn std::error::Error>>
+{
+	let mut results: HashMap<String, FileFingerprint> = HashMap::new();
+	let mut buffer: [u8; 8192] = [0u8; 8192];
+
+	for i in 0..archive.len()
+	{
+		let (name, crc32, size) = {
+			let zipped_file = archive.by_index_raw(i)?;
+
+			if zipped_file.is_dir()
+			{
+				continue;
+			}
+
+			(zipped_file.name().to_string(), zipped_file.crc32(), zipped_file.size())
+		};
+
+		let sha256: Option<String> = if strict
+		{
+			let mut zipped_file = archive.by_index(i)?;
+			let mut hasher = Sha256::new();
+
+			loop
+			{
+				let bytes_read = zipped_file.read(&mut buffer)?;
+
+				if bytes_read == 0
+				{
+					break;
+				}
+
+				hasher.update(&buffer[..bytes_read]);
+			}
+
+			Some(format!("{:x}", hasher.finalize()))
+		}
+		else
+		{
+			None
+		};
+
+		results.insert(name, FileFingerprint { crc32, size, sha256 }); // insert into HashMap
+	}
+
+	Ok(results)
+}
+
+
+/**
+* CIK of a file inside a SEC archive, for both `CIK##########.json` and its paginated
+* `CIK##########-submissions-###.json` older filings files
+* @param file_name {&str} File name inside the archive
+*/
+pub fn cik_from_file_name(file_name: &str) -> Option<String>
+{
+	let cik: &str = file_name.strip_prefix("CIK")?.get(..CIK_DIGITS)?;
+
+	if !cik.bytes().all(|b| b.is_ascii_digit())
+	{
+		return None;
+	}
+
+	Some(cik.to_string())
+}
+
+/**
+* Group file fingerprints by CIK, so a company's main file and its paginated files are handled as one unit and a
+* change in any of them marks the CIK as changed. Files that are not named after a CIK are left out.
+* Returns BTreeMap<cik, BTreeMap<file_name, FileFingerprint>>
+* @param file_names_to_fingerprints {HashMap<String, FileFingerprint>} Fingerprints of every file in an archive
+*/
+pub fn group_file_names_to_fingerprints_by_cik(
+	file_names_to_fingerprints: HashMap<String, FileFingerprint>
+) -> BTreeMap<String, BTreeMap<String, FileFingerprint>>
+{
+	let mut ciks: BTreeMap<String, BTreeMap<String, FileFingerprint>> = BTreeMap::new();
+
+	for (file_name, fingerprint) in file_names_to_fingerprints
+	{
+		let Some(cik) = cik_from_file_name(&file_name) else { continue };
+
+		ciks.entry(cik).or_default().insert(file_name, fingerprint);
+	}
+
+	ciks
+}
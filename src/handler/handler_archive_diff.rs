@@ -0,0 +1,567 @@
+use serde_json::Value;
+use std::collections::{ BTreeMap, BTreeSet };
+use std::fs::File;
+use std::path::{ Path, PathBuf };
+use zip::ZipArchive;
+
+use crate::{ log_info, log_warn };
+use crate::handler::file::zip::{ HandlerFileCompanyfactsZip, HandlerFileSubmissionsZip };
+use crate::handler::file::zip::zip_fingerprint::group_file_names_to_fingerprints_by_cik;
+use crate::schema::{
+	ArchiveDiff,
+	ArchiveDiffChanged,
+	ArchiveDiffFact,
+	ArchiveDiffField,
+	FileFingerprint,
+	SecArchive,
+	SubmissionsData,
+};
+
+
+/// (taxonomy, concept, unit, accession number, start, end, val)
+type FactKey = (String, String, String, String, Option<String>, String, String);
+
+/// (field name, its value as text)
+type SubmissionsDataField = (&'static str, fn(&SubmissionsData) -> String);
+
+
+/**
+* Every submission field the synchronizer writes besides tickers and filings, as the text the diff reports
+*/
+const SUBMISSIONS_DATA_FIELDS: [SubmissionsDataField; 27] = [
+	("name", |s| s.name.clone()),
+	("former_names", |s| s.former_names.iter().map(
+		|f|
+		{
+			format!(
+				"{} ({}..{})",
+				f.name,
+				f.from.map(|d| d.to_string()).unwrap_or_default(),
+				f.to.map(|d| d.to_string()).unwrap_or_default()
+			)
+		}
+	).collect::<Vec<String>>().join("; ")),
+	("business_street1", |s| s.business_street1.clone()),
+	("business_city", |s| s.business_city.clone()),
+	("business_state", |s| s.business_state.clone()),
+	("business_zip", |s| s.business_zip.clone()),
+	("business_country", |s| s.business_country.clone()),
+	("mailing_street1", |s| s.mailing_street1.clone()),
+	("mailing_city", |s| s.mailing_city.clone()),
+	("mailing_state", |s| s.mailing_state.clone()),
+	("mailing_zip", |s| s.mailing_zip.clone()),
+	("mailing_country", |s| s.mailing_country.clone()),
+	("phone", |s| s.phone.clone()),
+	("website", |s| s.website.clone()),
+	("investor_website", |s| s.investor_website.clone()),
+	("category", |s| s.category.clone()),
+	("description", |s| s.description.clone()),
+	("ein", |s| s.ein.clone()),
+	("entity_type", |s| s.entity_type.clone()),
+	("fiscal_year_end", |s| s.fiscal_year_end.clone()),
+	("flags", |s| s.flags.clone()),
+	("insider_transaction_for_issuer_exists", |s| s.insider_transaction_for_issuer_exists.to_string()),
+	("insider_transaction_for_owner_exists", |s| s.insider_transaction_for_owner_exists.to_string()),
+	("owner_org", |s| s.owner_org.clone()),
+	("sic", |s| s.sic.clone()),
+	("sic_description", |s| s.sic_description.clone()),
+	("state_of_incorporation", |s| s.state_of_incorporation.clone()),
+];
+
+
+/**
+* Compares two submissions.zip or two companyfacts.zip files (e.g. `old.companyfacts.zip` and `companyfacts.zip`)
+* without touching the database
+*/
+pub struct HandlerArchiveDiff
+{
+	path_old: PathBuf,
+	path_new: PathBuf,
+}
+
+
+impl HandlerArchiveDiff
+{
+
+	/**
+	* @visibility: Public
+	* @param path_old {PathBuf} Path to the older archive
+	* @param path_new {PathBuf} Path to the newer archive
+	*/
+	pub fn new(path_old: PathBuf, path_new: PathBuf) -> Self
+	{
+		Self { path_old, path_new }
+	}
+
+	/**
+	* @visibility: Internal
+	* Tell a submissions.zip from a companyfacts.zip by the shape of its first JSON file
+	* @param path {&Path} Path to the archive
+	*/
+	fn detect_archive(path: &Path) -> Result<SecArchive, Box<dyn std::error::Error>>
+	{
+		let mut archive = ZipArchive::new(File::open(path)?)?;
+
+		for i in 0..archive.len()
+		{
+			let mut zipped_file = archive.by_index(i)?;
+
+			if zipped_file.is_dir() || !zipped_file.name().ends_with(".json")
+			{
+				continue;
+			}
+
+			let json: Value = serde_json::from_reader(&mut zipped_file)?;
+
+			if json.get("facts").is_some()
+			{
+				return Ok(SecArchive::Companyfacts);
+			}
+
+			if json.get("filings").is_some()
+			{
+				return Ok(SecArchive::Submissions);
+			}
+		}
+
+		Err(format!("{} is neither a submissions.zip nor a companyfacts.zip", path.display()).into())
+	}
+
+	/**
+	* @visibility: Internal
+	* Split the CIKs of both archives into added, removed and changed
+	*/
+	fn compare_ciks(
+		old: &BTreeMap<String, BTreeMap<String, FileFingerprint>>,
+		new: &BTreeMap<String, BTreeMap<String, FileFingerprint>>,
+	) -> (Vec<String>, Vec<String>, Vec<String>)
+	{
+		let added: Vec<String> = new.keys().filter(|cik| !old.contains_key(*cik)).cloned().collect();
+
+		let removed: Vec<String> = old.keys().filter(|cik| !new.contains_key(*cik)).cloned().collect();
+
+		let changed: Vec<String> = new.iter().filter(
+			|(cik, files)| old.get(*cik).is_some_and(|old_files| old_files != *files)
+		).map(
+			|(cik, _)| cik.clone()
+		).collect();
+
+		(added, removed, changed)
+	}
+
+	/**
+	* @visibility: Internal
+	* "TICKER (EXCHANGE)" pairs of a submission
+	*/
+	fn ticker_exchange_pairs(submissions_data: &SubmissionsData) -> BTreeSet<String>
+	{
+		submissions_data.exchange_tickers.iter().map(|exchange_ticker| exchange_ticker.to_string()).collect()
+	}
+
+	/**
+	* @visibility: Internal
+	* Semantic summary of a changed submissions file
+	*/
+	fn diff_submissions_data(cik: &str, old: &SubmissionsData, new: &SubmissionsData) -> ArchiveDiffChanged
+	{
+		let old_accession_numbers: BTreeSet<&str> = old.filings.iter().map(|f| f.accession_number.as_str()).collect();
+
+		let new_accession_numbers: BTreeSet<&str> = new.filings.iter().map(|f| f.accession_number.as_str()).collect();
+
+		let old_tickers: BTreeSet<String> = Self::ticker_exchange_pairs(old);
+		let new_tickers: BTreeSet<String> = Self::ticker_exchange_pairs(new);
+
+		let mut fields_changed: Vec<ArchiveDiffField> = Vec::new();
+
+		for (field, value) in SUBMISSIONS_DATA_FIELDS
+		{
+			let old_value: String = value(old);
+			let new_value: String = value(new);
+
+			if old_value != new_value
+			{
+				fields_changed.push(ArchiveDiffField { field: field.to_string(), old: old_value, new: new_value });
+			}
+		}
+
+		ArchiveDiffChanged
+		{
+			cik: cik.to_string(),
+			new_accession_numbers: new_accession_numbers.difference(&old_accession_numbers).map(|a| a.to_string()).collect(),
+			tickers_added: new_tickers.difference(&old_tickers).cloned().collect(),
+			tickers_removed: old_tickers.difference(&new_tickers).cloned().collect(),
+			fields_changed,
+			new_facts: Vec::new(),
+		}
+	}
+
+	/**
+	* @visibility: Internal
+	* Every fact value of a companyfacts JSON across all taxonomies, concepts and units
+	*/
+	fn collect_facts(json_companyfacts: &Value) -> BTreeSet<FactKey>
+	{
+		let mut facts: BTreeSet<FactKey> = BTreeSet::new();
+
+		let Some(taxonomies) = json_companyfacts.get("facts").and_then(|v| v.as_object()) else { return facts };
+
+		for (taxonomy, concepts) in taxonomies
+		{
+			let Some(concepts) = concepts.as_object() else { continue };
+
+			for (concept, json_concept) in concepts
+			{
+				let Some(units) = json_concept.get("units").and_then(|v| v.as_object()) else { continue };
+
+				for (unit, values) in units
+				{
+					let Some(values) = values.as_array() else { continue };
+
+					for item in values
+					{
+						let get_str = |key: &str| item.get(key).and_then(|v| v.as_str()).map(String::from);
+
+						let val: String = match item.get("val")
+						{
+							Some(Value::String(s)) => s.clone(),
+							Some(v) => v.to_string(),
+							None => continue,
+						};
+
+						facts.insert(
+							(
+								taxonomy.clone(),
+								concept.clone(),
+								unit.clone(),
+								get_str("accn").unwrap_or_default(),
+								get_str("start"),
+								get_str("end").unwrap_or_default(),
+								val,
+							)
+						);
+					}
+				}
+			}
+		}
+
+		facts
+	}
+
+	/**
+	* @visibility: Internal
+	* Semantic summary of a changed companyfacts file
+	*/
+	fn diff_companyfacts(cik: &str, old: &Value, new: &Value) -> ArchiveDiffChanged
+	{
+		let old_facts: BTreeSet<FactKey> = Self::collect_facts(old);
+		let new_facts: BTreeSet<FactKey> = Self::collect_facts(new);
+
+		let mut fields_changed: Vec<ArchiveDiffField> = Vec::new();
+
+		let old_name: &str = old.get("entityName").and_then(|v| v.as_str()).unwrap_or("");
+		let new_name: &str = new.get("entityName").and_then(|v| v.as_str()).unwrap_or("");
+
+		if old_name != new_name
+		{
+			fields_changed.push(
+				ArchiveDiffField { field: "entityName".to_string(), old: old_name.to_string(), new: new_name.to_string() }
+			);
+		}
+
+		ArchiveDiffChanged
+		{
+			cik: cik.to_string(),
+			fields_changed,
+			new_facts: new_facts.difference(&old_facts).cloned().map(
+				|(taxonomy, concept, unit, accession_number, start, end, val)|
+				{
+					ArchiveDiffFact { taxonomy, concept, unit, accession_number, start, end, val }
+				}
+			).collect(),
+			..Default::default()
+		}
+	}
+
+	/**
+	* @visibility: Public
+	* Compare the two archives. Both must be of the same kind.
+	*/
+	pub fn diff(&self) -> Result<ArchiveDiff, Box<dyn std::error::Error>>
+	{
+		let archive: SecArchive = Self::detect_archive(&self.path_new)?;
+
+		if Self::detect_archive(&self.path_old)? != archive
+		{
+			return Err(
+				format!(
+					"{} and {} are not the same kind of archive",
+					self.path_old.display(),
+					self.path_new.display()
+				).into()
+			);
+		}
+
+		log_info!("Comparing {} archives {} and {}..", archive.as_str(), self.path_old.display(), self.path_new.display());
+
+		let changed: Vec<ArchiveDiffChanged>;
+		let added: Vec<String>;
+		let removed: Vec<String>;
+
+		match archive
+		{
+			SecArchive::Submissions =>
+			{
+				let mut handler_old = HandlerFileSubmissionsZip::new(self.path_old.clone())?;
+				let mut handler_new = HandlerFileSubmissionsZip::new(self.path_new.clone())?;
+
+				let old = group_file_names_to_fingerprints_by_cik(handler_old.compute_file_names_to_fingerprints(false)?);
+				let new = group_file_names_to_fingerprints_by_cik(handler_new.compute_file_names_to_fingerprints(false)?);
+
+				let changed_ciks: Vec<String>;
+
+				(added, removed, changed_ciks) = Self::compare_ciks(&old, &new);
+
+				changed = changed_ciks.iter().map(
+					|cik|
+					{
+						let file_name: String = format!("CIK{}.json", cik);
+
+						match (handler_old.extract_submissions_data(&file_name), handler_new.extract_submissions_data(&file_name))
+						{
+							(Ok(old), Ok(new)) => Self::diff_submissions_data(cik, &old, &new),
+							(Err(e), _) | (_, Err(e)) =>
+							{
+								log_warn!("Failed to parse {}. Reporting it as changed without a summary", e);
+
+								ArchiveDiffChanged { cik: cik.clone(), ..Default::default() }
+							},
+						}
+					}
+				).collect();
+			},
+			SecArchive::Companyfacts =>
+			{
+				let mut handler_old = HandlerFileCompanyfactsZip::new(self.path_old.clone())?;
+				let mut handler_new = HandlerFileCompanyfactsZip::new(self.path_new.clone())?;
+
+				let old = group_file_names_to_fingerprints_by_cik(handler_old.compute_file_names_to_fingerprints(false)?);
+				let new = group_file_names_to_fingerprints_by_cik(handler_new.compute_file_names_to_fingerprints(false)?);
+
+				let changed_ciks: Vec<String>;
+
+				(added, removed, changed_ciks) = Self::compare_ciks(&old, &new);
+
+				changed = changed_ciks.iter().map(
+					|cik|
+					{
+						let file_name: String = format!("CIK{}.json", cik);
+
+						match (handler_old.load_json_from_file(&file_name), handler_new.load_json_from_file(&file_name))
+						{
+							(Ok(old), Ok(new)) => Self::diff_companyfacts(cik, &old, &new),
+							(Err(e), _) | (_, Err(e)) =>
+							{
+								log_warn!("Failed to parse {}: {}. Reporting it as changed without a summary", file_name, e);
+
+								ArchiveDiffChanged { cik: cik.clone(), ..Default::default() }
+							},
+						}
+					}
+				).collect();
+			},
+		}
+
+		Ok(ArchiveDiff { archive, added, removed, changed })
+	}
+
+	/**
+	* @visibility: Public
+	* Human-readable rendering of a diff
+	* @param archive_diff {&ArchiveDiff} Result of `diff`
+	*/
+	pub fn format_text(archive_diff: &ArchiveDiff) -> String
+	{
+		let mut lines: Vec<String> = vec![
+			format!(
+				"{} diff: {} added, {} removed, {} changed",
+				archive_diff.archive.as_str(),
+				archive_diff.added.len(),
+				archive_diff.removed.len(),
+				archive_diff.changed.len()
+			)
+		];
+
+		lines.extend(archive_diff.added.iter().map(|cik| format!("+ CIK {}", cik)));
+
+		lines.extend(archive_diff.removed.iter().map(|cik| format!("- CIK {}", cik)));
+
+		for changed in &archive_diff.changed
+		{
+			lines.push(format!("~ CIK {}", changed.cik));
+
+			let before: usize = lines.len();
+
+			lines.extend(changed.new_accession_numbers.iter().map(|a| format!("    new filing {}", a)));
+
+			lines.extend(changed.tickers_added.iter().map(|t| format!("    ticker added {}", t)));
+
+			lines.extend(changed.tickers_removed.iter().map(|t| format!("    ticker removed {}", t)));
+
+			lines.extend(
+				changed.fields_changed.iter().map(|f| format!("    {}: \"{}\" -> \"{}\"", f.field, f.old, f.new))
+			);
+
+			lines.extend(
+				changed.new_facts.iter().map(
+					|f|
+					{
+						format!(
+							"    new fact {}:{} [{}] {}{} = {} (accn {})",
+							f.taxonomy,
+							f.concept,
+							f.unit,
+							f.start.as_ref().map(|s| format!("{}..", s)).unwrap_or_default(),
+							f.end,
+							f.val,
+							f.accession_number
+						)
+					}
+				)
+			);
+
+			if lines.len() == before
+			{
+				lines.push("    no differences in the tracked fields".to_string());
+			}
+		}
+
+		lines.join("\n")
+	}
+}
+
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	use crate::schema::JsonSubmissions;
+
+
+	fn submissions_data(submissions: &str) -> SubmissionsData
+	{
+		let json_submissions: JsonSubmissions = serde_json::from_str(submissions).unwrap();
+
+		HandlerFileSubmissionsZip::parse_submissions_data(&json_submissions, &[]).unwrap()
+	}
+
+	fn fingerprints(files: &[(&str, u32)]) -> BTreeMap<String, FileFingerprint>
+	{
+		files.iter().map(
+			|(file_name, crc32)| (file_name.to_string(), FileFingerprint { crc32: *crc32, size: 1, sha256: None })
+		).collect()
+	}
+
+	#[test]
+	fn ciks_are_split_into_added_removed_and_changed()
+	{
+		let old: BTreeMap<String, BTreeMap<String, FileFingerprint>> = BTreeMap::from(
+			[
+				(String::from("1"), fingerprints(&[("CIK0000000001.json", 1)])),
+				(String::from("2"), fingerprints(&[("CIK0000000002.json", 2)])),
+				(String::from("3"), fingerprints(&[("CIK0000000003.json", 3)])),
+			]
+		);
+
+		let new: BTreeMap<String, BTreeMap<String, FileFingerprint>> = BTreeMap::from(
+			[
+				(String::from("2"), fingerprints(&[("CIK0000000002.json", 2)])),
+				(String::from("3"), fingerprints(&[("CIK0000000003.json", 3), ("CIK0000000003-submissions-001.json", 4)])),
+				(String::from("4"), fingerprints(&[("CIK0000000004.json", 5)])),
+			]
+		);
+
+		let (added, removed, changed) = HandlerArchiveDiff::compare_ciks(&old, &new);
+
+		assert_eq!(added, vec!["4"]);
+		assert_eq!(removed, vec!["1"]);
+		assert_eq!(changed, vec!["3"]);
+	}
+
+	#[test]
+	fn every_synchronized_submission_field_is_compared()
+	{
+		let old: SubmissionsData = submissions_data(
+			r#"{
+				"cik": "1",
+				"name": "Co",
+				"tickers": ["CO"],
+				"exchanges": ["NYSE"],
+				"ein": "123",
+				"sic": "1000",
+				"insiderTransactionForIssuerExists": 0,
+				"filings": { "recent": {}, "files": [] }
+			}"#
+		);
+
+		let new: SubmissionsData = submissions_data(
+			r#"{
+				"cik": "1",
+				"name": "Co",
+				"tickers": ["CO"],
+				"exchanges": ["Nasdaq"],
+				"ein": "123",
+				"sic": "2000",
+				"insiderTransactionForIssuerExists": 1,
+				"addresses": { "mailing": { "city": "NY" } },
+				"formerNames": [{ "name": "Old Co", "from": "2000-01-01T00:00:00.000Z", "to": "2010-01-01T00:00:00.000Z" }],
+				"filings": { "recent": {}, "files": [] }
+			}"#
+		);
+
+		let archive_diff_changed: ArchiveDiffChanged = HandlerArchiveDiff::diff_submissions_data("1", &old, &new);
+
+		let fields: Vec<&str> = archive_diff_changed.fields_changed.iter().map(|f| f.field.as_str()).collect();
+
+		assert_eq!(fields, vec!["former_names", "mailing_city", "insider_transaction_for_issuer_exists", "sic"]);
+		assert_eq!(archive_diff_changed.fields_changed[0].new, "Old Co (2000-01-01..2010-01-01)");
+		assert_eq!(archive_diff_changed.tickers_added, vec!["CO (Nasdaq)"]);
+		assert_eq!(archive_diff_changed.tickers_removed, vec!["CO (NYSE)"]);
+	}
+
+	#[test]
+	fn format_text_lists_every_change_and_flags_untracked_ones()
+	{
+		let archive_diff: ArchiveDiff = ArchiveDiff
+		{
+			archive: SecArchive::Submissions,
+			added: vec![String::from("4")],
+			removed: vec![String::from("1")],
+			changed: vec![
+				ArchiveDiffChanged
+				{
+					cik: String::from("3"),
+					new_accession_numbers: vec![String::from("0000000003-24-000001")],
+					fields_changed: vec![
+						ArchiveDiffField { field: String::from("sic"), old: String::from("1000"), new: String::from("2000") },
+					],
+					..Default::default()
+				},
+				ArchiveDiffChanged { cik: String::from("5"), ..Default::default() },
+			],
+		};
+
+		assert_eq!(
+			HandlerArchiveDiff::format_text(&archive_diff),
+			[
+				"submissions diff: 1 added, 1 removed, 2 changed",
+				"+ CIK 4",
+				"- CIK 1",
+				"~ CIK 3",
+				"    new filing 0000000003-24-000001",
+				"    sic: \"1000\" -> \"2000\"",
+				"~ CIK 5",
+				"    no differences in the tracked fields",
+			].join("\n")
+		);
+	}
+}
@@ -1,18 +1,24 @@
-use chrono::{ Local };
+use chrono::{ Local, NaiveDate };
 
+use std::path::PathBuf;
 use std::sync::Arc;
 
+use crate::config::Config;
 use crate::database::database_connection::DatabaseConnection;
 use crate::database::table_security::TableSecurity;
 use crate::handler::HandlerApiSec;
 use crate::handler::UpdatedSecCompanyfactsAndSubmissions;
-use crate::handler::HandlerFilingCommonStockSharesOutstanding;
-use crate::handler::HandlerFilingEntityCommonStockSharesOutstanding;
 use crate::handler::HandlerSecurityExchangeTicker;
 use crate::handler::HandlerSecurityFiling;
-use crate::handler::data::handler_filing_assets::HandlerFilingAssets;
+use crate::database::table_filing_legacy::FilingLegacyTable;
+use crate::handler::data::handler_filing_fact::HandlerFilingFact;
+use crate::handler::data::handler_filing_legacy::HandlerFilingLegacy;
+use crate::handler::data::handler_sec_parse_quarantine::HandlerSecParseQuarantine;
 use crate::handler::data::handler_sec_submission_file_hash::HandlerSecSubmissionFileHash;
+use crate::handler::file::zip::{ HandlerFileCompanyfactsZip, HandlerFileSubmissionsZip };
+use crate::handler::file::zip::zip_fingerprint::group_file_names_to_fingerprints_by_cik;
 use crate::schema::Companyfacts;
+use crate::schema::{ FileFingerprint, SecArchive };
 use crate::schema::SubmissionsData;
 
 use crate::{ log_debug, log_ultradebug, log_error, log_info, log_warn };
@@ -20,7 +26,9 @@ use crate::handler::{ HandlerSecurity, SynchronizeSecurity };
 
 
 pub struct HandlerDatabaseSecuritySynchronizer
-{}
+{
+	config: Config,
+}
 
 
 impl HandlerDatabaseSecuritySynchronizer
@@ -28,10 +36,130 @@ impl HandlerDatabaseSecuritySynchronizer
 	/**
 	* @visibility: Public
 	*/
-	pub fn new() -> Self
+	pub fn new(config: Config) -> Self
 	{
 		Self
-		{}
+		{
+			config,
+		}
+	}
+
+
+	/**
+	* @visibility: Internal
+	* Log the identity of the security about to be synchronized
+	*/
+	fn log_submissions_data(&self, source: &str, submissions_data: &SubmissionsData)
+	{
+		log_info!("------------------------------------------------------------");
+		log_info!("Synchronizing {}", source);
+		log_info!("CIK: {}", submissions_data.cik);
+		log_info!("Name: {}", submissions_data.name);
+		log_info!(
+			"Tickers: {}",
+			submissions_data.exchange_tickers.iter().map(|t| t.to_string()).collect::<Vec<String>>().join(", ")
+		);
+	}
+
+	/**
+	* @visibility: Internal
+	* Write one company's security, tickers and filings to the database. Stops at the first write that fails, so the
+	* caller does not record the file as synchronized.
+	* @param as_of {&NaiveDate} Date the submissions were published, used to open and close ticker periods
+	*/
+	async fn synchronize_submissions_data(
+		&self,
+		db_connection: Arc<DatabaseConnection>,
+		submissions_data: SubmissionsData,
+		as_of: &NaiveDate,
+	) -> Result<(), Box<dyn std::error::Error>>
+	{
+		HandlerSecurity::new(db_connection.clone()).synchronize(
+			&SynchronizeSecurity {
+				cik: submissions_data.cik.clone(),
+				business_country: submissions_data.business_country,
+				business_city: submissions_data.business_city,
+				business_state: submissions_data.business_state,
+				business_street1: submissions_data.business_street1,
+				business_zip: submissions_data.business_zip,
+				mailing_country: submissions_data.mailing_country,
+				mailing_city: submissions_data.mailing_city,
+				mailing_state: submissions_data.mailing_state,
+				mailing_street1: submissions_data.mailing_street1,
+				mailing_zip: submissions_data.mailing_zip,
+				category: submissions_data.category,
+				description: submissions_data.description,
+				ein: submissions_data.ein,
+				entity_type: submissions_data.entity_type,
+				fiscal_year_end: submissions_data.fiscal_year_end,
+				flags: submissions_data.flags,
+				former_names: submissions_data.former_names,
+				insider_transaction_for_issuer_exists: submissions_data.insider_transaction_for_issuer_exists,
+				insider_transaction_for_owner_exists: submissions_data.insider_transaction_for_owner_exists,
+				investor_website: submissions_data.investor_website,
+				name: submissions_data.name,
+				owner_org: submissions_data.owner_org,
+				phone: submissions_data.phone,
+				sic: submissions_data.sic,
+				sic_description: submissions_data.sic_description,
+				state_of_incorporation: submissions_data.state_of_incorporation,
+				website: submissions_data.website,
+			},
+		).await.map_err(|e| format!("Failed to synchronize security: {}", e))?;
+
+		HandlerSecurityExchangeTicker::new(db_connection.clone()).synchronize(
+			&submissions_data.cik,
+			&submissions_data.exchange_tickers,
+			as_of,
+		).await.map_err(|e| format!("Failed to synchronize security_exchange_ticker: {}", e))?;
+
+		HandlerSecurityFiling::new(db_connection.clone()).synchronize(
+			&submissions_data.cik,
+			&submissions_data.filings
+		).await.map_err(|e| format!("Failed to synchronize security_filing: {}", e))?;
+
+		Ok(())
+	}
+
+	/**
+	* @visibility: Internal
+	* Write one company's facts to the database. Stops at the first table that fails, so the caller does not record the
+	* file as synchronized.
+	* Returns how many facts were dropped while parsing
+	*/
+	async fn synchronize_companyfacts(
+		&self,
+		db_connection: Arc<DatabaseConnection>,
+		companyfacts: Companyfacts
+	) -> Result<usize, Box<dyn std::error::Error>>
+	{
+		if companyfacts.dropped_facts > 0
+		{
+			log_warn!(
+				"Dropped {} fact(s) for CIK {} with a missing field or an unparseable value",
+				companyfacts.dropped_facts,
+				companyfacts.cik
+			);
+		}
+
+		HandlerFilingFact::new(db_connection.clone()).synchronize(
+			&companyfacts.facts,
+		).await.map_err(|e| format!("Failed to synchronize filing_fact: {}", e))?;
+
+		let legacy_facts = [
+			(FilingLegacyTable::Assets, &companyfacts.assets),
+			(FilingLegacyTable::CommonStockSharesOutstanding, &companyfacts.common_stock_shares_outstanding),
+			(FilingLegacyTable::EntityCommonStockSharesOutstanding, &companyfacts.entity_common_stock_shares_outstanding),
+		];
+
+		for (table, facts) in legacy_facts
+		{
+			HandlerFilingLegacy::new(db_connection.clone(), table).synchronize(facts).await.map_err(
+				|e| format!("Failed to synchronize {}: {}", table.name(), e)
+			)?;
+		}
+
+		Ok(companyfacts.dropped_facts)
 	}
 
 
@@ -42,163 +170,611 @@ impl HandlerDatabaseSecuritySynchronizer
 	{
 		log_info!("Building security profile at {}", Local::now().format("%Y-%m-%d %H:%M:%S"));
 
-		let handler_api_sec = HandlerApiSec::new();
+		let handler_api_sec = HandlerApiSec::new(&self.config)?;
 
-		let db_connection = Arc::new(DatabaseConnection::new().await?);
+		let updated_sec_companyfacts_and_submissions = handler_api_sec.get_updated_companyfacts_and_submissions().await?;
 
-		let handler_sec_submission_file_hash = HandlerSecSubmissionFileHash::new(db_connection.clone());
+		self.synchronize_archives(updated_sec_companyfacts_and_submissions).await
+	}
+
+	/**
+	* Run Creating Security Profile tasks from archives supplied on the command line. No HandlerApiSec is ever
+	* created on this path, so no outbound HTTP request can be made.
+	* @param path_submissions_zip {PathBuf} Path to a submissions.zip
+	* @param path_companyfacts_zip {PathBuf} Path to a companyfacts.zip
+	*/
+	pub async fn synchronize_offline(
+		&self,
+		path_submissions_zip: PathBuf,
+		path_companyfacts_zip: PathBuf,
+	) -> Result<(), Box<dyn std::error::Error>>
+	{
+		log_info!("Building security profile offline at {}", Local::now().format("%Y-%m-%d %H:%M:%S"));
+
+		log_info!("Initializing a HandlerFileSubmissionsZip for {}..", path_submissions_zip.display());
+
+		let handler_file_submissions_zip = HandlerFileSubmissionsZip::new(path_submissions_zip)?;
 
+		log_info!("Initializing a HandlerFileCompanyfactsZip for {}..", path_companyfacts_zip.display());
+
+		let handler_file_companyfacts_zip = HandlerFileCompanyfactsZip::new(path_companyfacts_zip)?;
+
+		self.synchronize_archives(
+			UpdatedSecCompanyfactsAndSubmissions
+			{
+				handler_file_submissions_zip,
+				handler_file_companyfacts_zip,
+			}
+		).await
+	}
+
+	/**
+	* @visibility: Internal
+	* Synchronize the database from already opened archives, wherever they came from
+	*/
+	async fn synchronize_archives(
+		&self,
+		updated_sec_companyfacts_and_submissions: UpdatedSecCompanyfactsAndSubmissions,
+	) -> Result<(), Box<dyn std::error::Error>>
+	{
 		let UpdatedSecCompanyfactsAndSubmissions
 		{
 			mut handler_file_companyfacts_zip,
 			mut handler_file_submissions_zip,
-		} = handler_api_sec.get_updated_companyfacts_and_submissions().await?;
+		} = updated_sec_companyfacts_and_submissions;
 
-		let submissions_file_names_to_hashs = handler_file_submissions_zip.compute_file_names_to_hashes()?;
+		let db_connection = Arc::new(DatabaseConnection::new(&self.config.database).await?);
 
-		for (s_file_name, s_file_hash) in submissions_file_names_to_hashs
-		{
-			log_ultradebug!("Processing submissions/{}", s_file_name);
+		let handler_sec_submission_file_hash = HandlerSecSubmissionFileHash::new(db_connection.clone());
 
-			let submissions_data: SubmissionsData = handler_file_submissions_zip.extract_submissions_data(
-				&s_file_name
-			)?;
+		let handler_sec_parse_quarantine = HandlerSecParseQuarantine::new(db_connection.clone());
+
+		// A company's main file and its paginated CIK##########-submissions-###.json files are one unit
+		let submissions_ciks_to_file_fingerprints = group_file_names_to_fingerprints_by_cik(
+			handler_file_submissions_zip.compute_file_names_to_fingerprints(self.config.sync.strict_sha256)?
+		);
+
+		let companyfacts_file_names_to_fingerprints = handler_file_companyfacts_zip.compute_file_names_to_fingerprints(
+			self.config.sync.strict_sha256
+		)?;
+
+		let mut dropped_facts: usize = 0;
 
-			if submissions_data.tickers.is_empty()
+		for (cik, s_file_fingerprints) in submissions_ciks_to_file_fingerprints
+		{
+			let s_file_name: String = format!("CIK{}.json", cik);
+
+			if !s_file_fingerprints.contains_key(&s_file_name)
 			{
-				log_ultradebug!("No tickers found in submissions/{}, skipping..", s_file_name);
+				log_warn!(
+					"submissions/{} not found but {} paginated file(s) of it are, skipping..",
+					s_file_name,
+					s_file_fingerprints.len()
+				);
 
 				continue;
 			}
 
-			log_info!("------------------------------------------------------------");
-			log_info!("Synchronizing submissions/{}", s_file_name);
-			log_info!("CIK: {}", submissions_data.cik);
-			log_info!("Name: {}", submissions_data.name);
-			log_info!("Tickers: {}", submissions_data.tickers.join(", "));
-			log_info!("Exchanges: {}", submissions_data.exchanges.join(", "));
+			log_ultradebug!("Processing submissions/{} with {} file(s)", s_file_name, s_file_fingerprints.len());
+
+			let c_file_fingerprint: Option<&FileFingerprint> = companyfacts_file_names_to_fingerprints.get(&s_file_name);
 
-			let mut synchronize_required: bool = false;
+			let mut synchronize_submissions_required: bool = true;
+			let mut synchronize_companyfacts_required: bool = c_file_fingerprint.is_some();
 
-			// Search database for security with cik
-			if let Some(_) = TableSecurity::new(db_connection.clone()).get_by_cik(&submissions_data.cik).await?
+			// Search database for security with cik, which the submissions JSON stores without zero padding
+			if TableSecurity::new(db_connection.clone()).get_by_cik(cik.trim_start_matches('0')).await?.is_some()
 			{
-				if !handler_sec_submission_file_hash.hash_exists(&s_file_name, &s_file_hash).await?
+				if handler_sec_submission_file_hash.group_fingerprints_exist(
+					SecArchive::Submissions,
+					&s_file_fingerprints
+				).await?
 				{
-					log_debug!("Hash NOT found in table sec_submission_file_hash");
+					log_debug!("Submissions fingerprint found in table sec_submission_file_hash");
 
-					synchronize_required = true;
+					synchronize_submissions_required = false;
 				}
 				else
 				{
-					log_debug!("Hash found in table sec_submission_file_hash");
+					log_debug!("Submissions fingerprint NOT found in table sec_submission_file_hash");
+				}
+
+				if let Some(c_file_fingerprint) = c_file_fingerprint
+				{
+					if handler_sec_submission_file_hash.fingerprint_exists(
+						SecArchive::Companyfacts,
+						&s_file_name,
+						c_file_fingerprint
+					).await?
+					{
+						log_debug!("Companyfacts fingerprint found in table sec_submission_file_hash");
+
+						synchronize_companyfacts_required = false;
+					}
+					else
+					{
+						log_debug!("Companyfacts fingerprint NOT found in table sec_submission_file_hash");
+					}
 				}
-			}
-			else
-			{
-				synchronize_required = true;
 			}
 
-			if !synchronize_required
+			if !synchronize_submissions_required && !synchronize_companyfacts_required
 			{
-				log_info!("[SKIP] Synchronize not required. Skipping");
+				log_info!("[SKIP] Synchronize of {} not required. Skipping", s_file_name);
 
 				continue;
 			}
 
-			log_info!("Synchronize required");
-
-			if let Err(e) = HandlerSecurity::new(db_connection.clone()).synchronize(
-				&SynchronizeSecurity {
-					cik: submissions_data.cik.clone(),
-					business_country: submissions_data.business_country,
-					business_city: submissions_data.business_city,
-					business_state: submissions_data.business_state,
-					business_street1: submissions_data.business_street1,
-					business_zip: submissions_data.business_zip,
-					description: submissions_data.description,
-					ein: submissions_data.ein,
-					entity_type: submissions_data.entity_type,
-					name: submissions_data.name,
-					phone: submissions_data.phone,
-					sic: submissions_data.sic,
-					website: submissions_data.website,
+			let submissions_data: SubmissionsData = match handler_file_submissions_zip.extract_submissions_data(
+				&s_file_name
+			)
+			{
+				Ok(submissions_data) => submissions_data,
+				Err(e) =>
+				{
+					handler_sec_parse_quarantine.quarantine(SecArchive::Submissions, &e).await?;
+
+					continue;
 				},
-			).await
+			};
+
+			if submissions_data.exchange_tickers.is_empty()
 			{
-				log_error!("Failed to synchronize security: {}", e);
+				log_ultradebug!("No tickers found in submissions/{}, skipping..", s_file_name);
+
+				continue;
 			}
 
-			if let Err(e) = HandlerSecurityExchangeTicker::new(db_connection.clone()).synchronize(
-				&submissions_data.cik,
-				&submissions_data.exchanges,
-				&submissions_data.tickers,
-			).await
+			self.log_submissions_data(&format!("submissions/{}", s_file_name), &submissions_data);
+
+			if c_file_fingerprint.is_none()
 			{
-				log_error!("Failed to synchronize security_exchange_ticker: {}", e);
+				log_warn!("{} not found in companyfacts.zip", &s_file_name);
 			}
 
-			if let Err(e) = HandlerSecurityFiling::new(db_connection.clone()).synchronize(
-				&submissions_data.cik,
-				&submissions_data.filings
-			).await
+			if synchronize_submissions_required
 			{
-				log_error!("Failed to synchronize security_filing with error: {}", e);
+				log_info!("Synchronize of submissions required");
+
+				// Date ticker periods by when SEC generated the file, not by when this run happens to read the archive
+				let as_of: NaiveDate = match handler_file_submissions_zip.file_modified_date(&s_file_name)
+				{
+					Ok(Some(as_of)) => as_of,
+					Ok(None) =>
+					{
+						log_warn!("submissions/{} has no modified time, dating its tickers today", s_file_name);
+
+						Local::now().date_naive()
+					},
+					Err(e) =>
+					{
+						log_warn!(
+							"Failed to read the modified time of submissions/{}, dating its tickers today: {}",
+							s_file_name,
+							e
+						);
+
+						Local::now().date_naive()
+					},
+				};
+
+				for file_name in s_file_fingerprints.keys()
+				{
+					handler_sec_parse_quarantine.release(SecArchive::Submissions, file_name).await?;
+				}
+
+				// Without its fingerprints the file is synchronized again next run
+				if let Err(e) = self.synchronize_submissions_data(db_connection.clone(), submissions_data, &as_of).await
+				{
+					log_error!("{}, submissions/{} will be retried next run", e, s_file_name);
+				}
+				else if let Err(e) = handler_sec_submission_file_hash.synchronize_group(
+					SecArchive::Submissions,
+					&s_file_fingerprints
+				).await
+				{
+					log_error!("Failed to synchronize sec_submission_file_hash: {}", e);
+				}
 			}
 
-			if handler_file_companyfacts_zip.file_exists(&s_file_name)
+			if let (true, Some(c_file_fingerprint)) = (synchronize_companyfacts_required, c_file_fingerprint)
 			{
-				let companyfacts: Option<Companyfacts> = Some(handler_file_companyfacts_zip.extract_data(&s_file_name)?);
+				log_info!("Synchronize of companyfacts required");
 
-				if let Some(companyfacts) = companyfacts
+				let companyfacts: Companyfacts = match handler_file_companyfacts_zip.extract_data(
+					&s_file_name,
+					&self.config.facts.concepts
+				)
 				{
-					if let Err(e) = HandlerFilingAssets::new(db_connection.clone()).synchronize(
-						&companyfacts.assets,
-					).await
+					Ok(companyfacts) => companyfacts,
+					Err(e) =>
 					{
-						log_error!("Failed to synchronize filing_assets: {}", e);
-					}
+						handler_sec_parse_quarantine.quarantine(SecArchive::Companyfacts, &e).await?;
 
-					if let Err(e) = HandlerFilingCommonStockSharesOutstanding::new(
-						db_connection.clone()
-					).synchronize(
-						&companyfacts.common_stock_shares_outstanding,
-					).await
-					{
-						log_error!("Failed to synchronize filing_common_stock_shares_outstanding: {}", e);
-					}
+						continue;
+					},
+				};
+
+				handler_sec_parse_quarantine.release(SecArchive::Companyfacts, &s_file_name).await?;
 
-					if let Err(e) = HandlerFilingEntityCommonStockSharesOutstanding::new(
-						db_connection.clone()
-					).synchronize(
-						&companyfacts.entity_common_stock_shares_outstanding,
-					).await
+				match self.synchronize_companyfacts(db_connection.clone(), companyfacts).await
+				{
+					Ok(dropped) =>
 					{
-						log_error!(
-							"Failed to synchronize filing_entity_common_stock_shares_outstanding: {}",
-							e
-						);
-					}
+						dropped_facts += dropped;
+
+						if let Err(e) = handler_sec_submission_file_hash.synchronize(
+							SecArchive::Companyfacts,
+							&s_file_name,
+							c_file_fingerprint
+						).await
+						{
+							log_error!("Failed to synchronize sec_submission_file_hash: {}", e);
+						}
+					},
+					Err(e) => log_error!("{}, companyfacts/{} will be retried next run", e, s_file_name),
 				}
 			}
-			else
+		}
+
+		let quarantined = handler_sec_parse_quarantine.list().await?;
+
+		if !quarantined.is_empty()
+		{
+			log_warn!("{} file(s) are in quarantine and will be retried next run:", quarantined.len());
+
+			for q in &quarantined
+			{
+				log_warn!(
+					"  {}/{} {}[{}] = {:?} (attempts: {}): {}",
+					q.archive,
+					q.file_name,
+					q.field,
+					q.item_index.map(|i| i.to_string()).unwrap_or_default(),
+					q.raw_value.as_deref().unwrap_or(""),
+					q.attempts,
+					q.message
+				);
+			}
+		}
+
+		db_connection.close().await?;
+
+		if dropped_facts > 0
+		{
+			log_warn!("{} fact(s) were dropped in total, see the warnings above for the CIKs", dropped_facts);
+		}
+
+		log_info!("Security profiles built successfully");
+
+		Ok(())
+	}
+
+	/**
+	* Synchronize only the given CIKs through the data.sec.gov submissions and companyfacts APIs, without touching
+	* the bulk archives
+	* @param ciks {&[String]} CIKs, with or without the CIK prefix and zero padding
+	*/
+	pub async fn synchronize_ciks(&self, ciks: &[String]) -> Result<(), Box<dyn std::error::Error>>
+	{
+		log_info!("Synchronizing {} CIK(s) at {}", ciks.len(), Local::now().format("%Y-%m-%d %H:%M:%S"));
+
+		let handler_api_sec = HandlerApiSec::new(&self.config)?;
+
+		let db_connection = Arc::new(DatabaseConnection::new(&self.config.database).await?);
+
+		let mut dropped_facts: usize = 0;
+
+		for cik in ciks
+		{
+			let submissions_data: SubmissionsData = match handler_api_sec.get_submissions_data(cik).await
 			{
-				log_warn!("{} not found in companyfacts.zip file not found.", &s_file_name);
+				Ok(Some(submissions_data)) => submissions_data,
+				Ok(None) =>
+				{
+					log_warn!("No submissions found for CIK {}, skipping..", cik);
+
+					continue;
+				},
+				Err(e) =>
+				{
+					log_error!("Failed to fetch submissions for CIK {}: {}", cik, e);
+
+					continue;
+				},
+			};
+
+			if submissions_data.exchange_tickers.is_empty()
+			{
+				log_warn!("No tickers found for CIK {}, skipping..", cik);
+
+				continue;
 			}
 
-			if let Err(e) = handler_sec_submission_file_hash.synchronize(
-				&s_file_name.to_string(),
-				&s_file_hash.to_string()
+			self.log_submissions_data(&format!("CIK {} from the SEC API", cik), &submissions_data);
+
+			let companyfacts: Option<Companyfacts> = match handler_api_sec.get_companyfacts(cik).await
+			{
+				Ok(Some(companyfacts)) => Some(companyfacts),
+				Ok(None) =>
+				{
+					log_warn!("No companyfacts found for CIK {}", cik);
+
+					None
+				},
+				Err(e) =>
+				{
+					log_error!("Failed to fetch companyfacts for CIK {}: {}", cik, e);
+
+					None
+				},
+			};
+
+			// The API serves the current submissions, so they are as of today
+			if let Err(e) = self.synchronize_submissions_data(
+				db_connection.clone(),
+				submissions_data,
+				&Local::now().date_naive()
 			).await
 			{
-				log_error!("Failed to synchronize sec_submission_file_hash: {}", e);
+				log_error!("{} for CIK {}", e, cik);
+			}
+
+			if let Some(companyfacts) = companyfacts
+			{
+				match self.synchronize_companyfacts(db_connection.clone(), companyfacts).await
+				{
+					Ok(dropped) => dropped_facts += dropped,
+					Err(e) => log_error!("{} for CIK {}", e, cik),
+				}
 			}
 		}
 
 		db_connection.close().await?;
 
-		log_info!("Security profiles built successfully");
+		if dropped_facts > 0
+		{
+			log_warn!("{} fact(s) were dropped in total, see the warnings above for the CIKs", dropped_facts);
+		}
+
+		log_info!("CIK synchronization completed");
 
 		Ok(())
 	}
 }
+
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	use crate::config::ConfigDatabase;
+	use crate::database::table_filing_fact::TableFilingFact;
+	use crate::schema::{ JsonSubmissions, SubmissionsDataExchangeTicker };
+	use rust_decimal::Decimal;
+	use std::str::FromStr;
+
+
+	const SUBMISSIONS: &str = r#"{
+		"cik": "1",
+		"name": "Co",
+		"tickers": ["CO", "COB"],
+		"exchanges": ["NYSE", null],
+		"addresses": {"business": {"street1": "1 St", "city": "SF", "stateOrCountry": "CA", "zipCode": "1"}},
+		"filings": {
+			"recent": {
+				"accessionNumber": ["0000000001-24-000001"],
+				"filingDate": ["2024-02-01"],
+				"form": ["10-K"],
+				"reportDate": ["2023-12-31"],
+				"acceptanceDateTime": ["2024-02-01T10:00:00.000Z"],
+				"act": ["34"],
+				"fileNumber": ["001-00001"],
+				"filmNumber": ["24000001"],
+				"items": [""],
+				"size": [123],
+				"isXBRL": [1],
+				"isInlineXBRL": [1],
+				"primaryDocument": ["co-20231231.htm"],
+				"primaryDocDescription": ["10-K"]
+			},
+			"files": []
+		}
+	}"#;
+
+	const COMPANYFACTS: &str = r#"{
+		"cik": 1,
+		"entityName": "Co",
+		"facts": {
+			"us-gaap": {
+				"Assets": {
+					"units": {
+						"USD": [
+							{
+								"accn": "0000000001-24-000001",
+								"end": "2023-12-31",
+								"fy": 2023,
+								"fp": "FY",
+								"val": 1234567890123.1234567891,
+								"filed": "2024-02-01",
+								"form": "10-K",
+								"frame": "CY2023Q4I"
+							}
+						]
+					}
+				}
+			}
+		}
+	}"#;
+
+
+	async fn memory_db_connection() -> Arc<DatabaseConnection>
+	{
+		let config_database = ConfigDatabase { url: Some(String::from("sqlite::memory:")), ..ConfigDatabase::default() };
+
+		let db_connection = Arc::new(DatabaseConnection::new(&config_database).await.unwrap());
+
+		db_connection.storage().migrations_run().await.unwrap();
+
+		db_connection
+	}
+
+	fn submissions_data(submissions: &str) -> SubmissionsData
+	{
+		let json_submissions: JsonSubmissions = serde_json::from_str(submissions).unwrap();
+
+		HandlerFileSubmissionsZip::parse_submissions_data(&json_submissions, &[]).unwrap()
+	}
+
+	/**
+	* Migrate a fresh in-memory database and sync the fixture submission and companyfacts into it twice, so the second
+	* pass has to find every row the first one wrote
+	*/
+	async fn migrate_and_sync_fixture() -> Arc<DatabaseConnection>
+	{
+		let synchronizer = HandlerDatabaseSecuritySynchronizer::new(Config::default());
+
+		let db_connection = memory_db_connection().await;
+
+		let as_of = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+
+		for _ in 0..2
+		{
+			synchronizer.synchronize_submissions_data(
+				db_connection.clone(),
+				submissions_data(SUBMISSIONS),
+				&as_of
+			).await.unwrap();
+
+			let companyfacts: Companyfacts = HandlerFileCompanyfactsZip::parse_companyfacts(
+				COMPANYFACTS.as_bytes(),
+				&synchronizer.config.facts.concepts
+			).unwrap();
+
+			assert_eq!(synchronizer.synchronize_companyfacts(db_connection.clone(), companyfacts).await.unwrap(), 0);
+		}
+
+		db_connection
+	}
+
+	#[tokio::test]
+	async fn migrate_up_and_sync_round_trip_on_a_memory_database()
+	{
+		let db_connection = migrate_and_sync_fixture().await;
+
+		let as_of = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+
+		assert!(TableSecurity::new(db_connection.clone()).get_by_cik("1").await.unwrap().is_some());
+
+		let listed = HandlerSecurityExchangeTicker::new(db_connection.clone()).find_by_ticker_on_date(
+			"CO",
+			&as_of
+		).await.unwrap();
+
+		assert_eq!(listed.len(), 1);
+		assert_eq!(listed[0].security_cik, "1");
+		assert_eq!(listed[0].exchange.as_deref(), Some("NYSE"));
+
+		let companyfacts: Companyfacts = HandlerFileCompanyfactsZip::parse_companyfacts(
+			COMPANYFACTS.as_bytes(),
+			&Config::default().facts.concepts
+		).unwrap();
+
+		assert_eq!(companyfacts.assets[0].val, Decimal::from_str("1234567890123.1234567891").unwrap());
+
+		for fact in &companyfacts.facts
+		{
+			assert!(TableFilingFact::new(db_connection.clone()).read_row(fact).await.unwrap().is_some());
+		}
+	}
+
+	#[tokio::test]
+	async fn a_failed_write_is_returned_instead_of_logged()
+	{
+		let synchronizer = HandlerDatabaseSecuritySynchronizer::new(Config::default());
+
+		let db_connection = memory_db_connection().await;
+
+		let companyfacts: Companyfacts = HandlerFileCompanyfactsZip::parse_companyfacts(
+			COMPANYFACTS.replace("2023-12-31", "2023-13-31").as_bytes(),
+			&synchronizer.config.facts.concepts
+		).unwrap();
+
+		let error = synchronizer.synchronize_companyfacts(db_connection.clone(), companyfacts).await.unwrap_err();
+
+		assert!(error.to_string().starts_with("Failed to synchronize filing_fact"), "{}", error);
+	}
+
+	#[tokio::test]
+	async fn exchange_tickers_are_reconciled_as_a_set()
+	{
+		let synchronizer = HandlerDatabaseSecuritySynchronizer::new(Config::default());
+
+		let db_connection = migrate_and_sync_fixture().await;
+
+		let handler_security_exchange_ticker = HandlerSecurityExchangeTicker::new(db_connection.clone());
+
+		let held_on = |ticker: &'static str, date: &'static str|
+		{
+			let handler_security_exchange_ticker = &handler_security_exchange_ticker;
+
+			async move
+			{
+				handler_security_exchange_ticker.find_by_ticker_on_date(
+					ticker,
+					&NaiveDate::from_str(date).unwrap()
+				).await.unwrap().into_iter().map(|row| row.exchange).collect::<Vec<Option<String>>>()
+			}
+		};
+
+		// CO moves from NYSE to Nasdaq, COB is delisted and COC is listed
+		let mut moved: SubmissionsData = submissions_data(SUBMISSIONS);
+
+		moved.exchange_tickers = vec![
+			SubmissionsDataExchangeTicker { exchange: Some(String::from("Nasdaq")), ticker: String::from("CO") },
+			SubmissionsDataExchangeTicker { exchange: Some(String::from("NYSE")), ticker: String::from("COC") },
+		];
+
+		synchronizer.synchronize_submissions_data(
+			db_connection.clone(),
+			moved,
+			&NaiveDate::from_ymd_opt(2024, 6, 1).unwrap()
+		).await.unwrap();
+
+		assert_eq!(held_on("CO", "2024-05-31").await, vec![Some(String::from("NYSE"))]);
+		assert_eq!(held_on("CO", "2024-06-01").await, vec![Some(String::from("Nasdaq"))]);
+		assert_eq!(held_on("COB", "2024-05-31").await, vec![None]);
+		assert!(held_on("COB", "2024-06-01").await.is_empty());
+		assert!(held_on("COC", "2024-05-31").await.is_empty());
+		assert_eq!(held_on("COC", "2024-06-01").await, vec![Some(String::from("NYSE"))]);
+
+		// The same pairs in another order change nothing, so the Nasdaq period still starts on 2024-06-01
+		let mut unchanged: SubmissionsData = submissions_data(SUBMISSIONS);
+
+		unchanged.exchange_tickers = vec![
+			SubmissionsDataExchangeTicker { exchange: Some(String::from("NYSE")), ticker: String::from("COC") },
+			SubmissionsDataExchangeTicker { exchange: Some(String::from("Nasdaq")), ticker: String::from("CO") },
+		];
+
+		synchronizer.synchronize_submissions_data(
+			db_connection.clone(),
+			unchanged,
+			&NaiveDate::from_ymd_opt(2024, 7, 1).unwrap()
+		).await.unwrap();
+
+		assert_eq!(held_on("CO", "2024-06-15").await, vec![Some(String::from("Nasdaq"))]);
+		assert_eq!(held_on("COC", "2024-06-15").await, vec![Some(String::from("NYSE"))]);
+
+		// An older submission read after the newer one leaves the periods alone
+		synchronizer.synchronize_submissions_data(
+			db_connection.clone(),
+			submissions_data(SUBMISSIONS),
+			&NaiveDate::from_ymd_opt(2024, 5, 1).unwrap()
+		).await.unwrap();
+
+		assert_eq!(held_on("CO", "2024-05-15").await, vec![Some(String::from("NYSE"))]);
+		assert_eq!(held_on("CO", "2024-06-15").await, vec![Some(String::from("Nasdaq"))]);
+		assert!(held_on("COB", "2024-06-15").await.is_empty());
+		assert_eq!(held_on("COC", "2024-06-15").await, vec![Some(String::from("NYSE"))]);
+	}
+}
@@ -1,52 +1,192 @@
 use std::time::Duration;
 
-use chrono::{ DateTime, Local, NaiveTime };
+use chrono::{ DateTime, Local, NaiveDateTime, NaiveTime, TimeZone };
 
 
 pub type Seconds = std::time::Duration;
 
 
 pub struct HandlerTime
-{}
+{
+	target_time: NaiveTime,
+}
 
 
 impl HandlerTime
 {
 	/**
 	* @visibility: Public
+	* @param hour {u32} Hour of the daily run in local time, checked by `Config::validate`
+	* @param minute {u32} Minute of the daily run in local time, checked by `Config::validate`
 	*/
-	pub fn new() -> Self
+	pub fn new(hour: u32, minute: u32) -> Result<Self, Box<dyn std::error::Error>>
 	{
-		Self
-		{}
+		let target_time: NaiveTime = NaiveTime::from_hms_opt(hour, minute, 0).ok_or_else(
+			|| format!("schedule {:02}:{:02} is not a valid time of day", hour, minute)
+		)?;
+
+		Ok(Self { target_time })
 	}
 
 
 	/**
-	* Calculate the time until the next 4am and return it as a Duration in seconds
+	* Calculate the time until the next scheduled run and return it as a Duration in seconds
 	*/
-	pub fn calculate_seconds_until_next_4am(&self) -> Seconds
+	pub fn calculate_seconds_until_next_run(&self) -> Seconds
 	{
 		let now: DateTime<Local> = Local::now();
-		let target_time = NaiveTime::from_hms_opt(4, 0, 0).expect("Valid time");
 
-		let today_4am = now.date_naive().and_time(target_time);
-		let tomorrow_4am = (now.date_naive() + chrono::Duration::days(1)).and_time(target_time);
+		let next_run: DateTime<Local> = Self::next_run_after(&now, self.target_time);
+
+		next_run.signed_duration_since(now).to_std().unwrap_or(Duration::ZERO)
+	}
 
-		let target_datetime = if now.time() < target_time
+
+	/**
+	* The first instant after `now` whose local time is `target_time`
+	* A target inside a DST gap runs at the first valid minute after it, a target repeated by the
+	* fall-back runs at its earlier occurrence only
+	*/
+	fn next_run_after<Tz: TimeZone>(now: &DateTime<Tz>, target_time: NaiveTime) -> DateTime<Tz>
+	{
+		let mut date = now.date_naive();
+
+		loop
 		{
-			// If it's before 4am today, schedule for today at 4am
-			today_4am
+			let mut candidate: NaiveDateTime = date.and_time(target_time);
+
+			// A DST gap lasts at most a few hours, past it the local time resolves again
+			let next_run: DateTime<Tz> = loop
+			{
+				match now.timezone().from_local_datetime(&candidate).earliest()
+				{
+					Some(next_run) => break next_run,
+					None => candidate += chrono::Duration::minutes(1),
+				}
+			};
+
+			if next_run > *now
+			{
+				return next_run;
+			}
+
+			date += chrono::Duration::days(1);
 		}
-		else
+	}
+}
+
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	use chrono::{ FixedOffset, LocalResult, NaiveDate };
+
+
+	/**
+	* Central European time, shifting to summer time at 02:00 on 2024-03-31 and back at 03:00 on 2024-10-27
+	*/
+	#[derive(Clone, Copy, Debug)]
+	struct Cet;
+
+	impl Cet
+	{
+		fn offset_at_utc(utc: &NaiveDateTime) -> FixedOffset
 		{
-			// If it's after 4am today, schedule for tomorrow at 4am
-			tomorrow_4am
-		};
+			let summer_from: NaiveDateTime = NaiveDate::from_ymd_opt(2024, 3, 31).unwrap().and_hms_opt(1, 0, 0).unwrap();
+			let summer_to: NaiveDateTime = NaiveDate::from_ymd_opt(2024, 10, 27).unwrap().and_hms_opt(1, 0, 0).unwrap();
+
+			let hours: i32 = if *utc >= summer_from && *utc < summer_to { 2 } else { 1 };
 
-		let target_local = target_datetime.and_local_timezone(Local).unwrap();
-		let duration_until_target = target_local.signed_duration_since(now);
+			FixedOffset::east_opt(hours * 3600).unwrap()
+		}
+	}
+
+	impl TimeZone for Cet
+	{
+		type Offset = FixedOffset;
 
-		return Duration::from_secs(duration_until_target.num_seconds() as u64);
+		fn from_offset(_: &FixedOffset) -> Self
+		{
+			Cet
+		}
+
+		fn offset_from_local_date(&self, local: &NaiveDate) -> LocalResult<FixedOffset>
+		{
+			self.offset_from_local_datetime(&local.and_hms_opt(12, 0, 0).unwrap())
+		}
+
+		fn offset_from_local_datetime(&self, local: &NaiveDateTime) -> LocalResult<FixedOffset>
+		{
+			let offsets: Vec<FixedOffset> = [3600, 7200].iter()
+				.map(|seconds| FixedOffset::east_opt(*seconds).unwrap())
+				.filter(|offset| Self::offset_at_utc(&(*local - *offset)) == *offset)
+				.collect();
+
+			match offsets[..]
+			{
+				[offset] => LocalResult::Single(offset),
+				// Summer time maps the repeated local hour to the earlier instant
+				[winter, summer] => LocalResult::Ambiguous(summer, winter),
+				_ => LocalResult::None,
+			}
+		}
+
+		fn offset_from_utc_date(&self, utc: &NaiveDate) -> FixedOffset
+		{
+			Self::offset_at_utc(&utc.and_hms_opt(12, 0, 0).unwrap())
+		}
+
+		fn offset_from_utc_datetime(&self, utc: &NaiveDateTime) -> FixedOffset
+		{
+			Self::offset_at_utc(utc)
+		}
+	}
+
+
+	fn at(month: u32, day: u32, hour: u32, minute: u32) -> DateTime<Cet>
+	{
+		Cet.from_local_datetime(&NaiveDate::from_ymd_opt(2024, month, day).unwrap().and_hms_opt(hour, minute, 0).unwrap())
+			.earliest()
+			.unwrap()
+	}
+
+	fn target(hour: u32, minute: u32) -> NaiveTime
+	{
+		NaiveTime::from_hms_opt(hour, minute, 0).unwrap()
+	}
+
+	#[test]
+	fn runs_today_before_the_target_and_tomorrow_from_it()
+	{
+		assert_eq!(HandlerTime::next_run_after(&at(6, 1, 3, 0), target(4, 0)), at(6, 1, 4, 0));
+		assert_eq!(HandlerTime::next_run_after(&at(6, 1, 4, 0), target(4, 0)), at(6, 2, 4, 0));
+		assert_eq!(HandlerTime::next_run_after(&at(6, 1, 23, 0), target(4, 0)), at(6, 2, 4, 0));
+	}
+
+	#[test]
+	fn a_target_in_the_spring_gap_runs_when_the_clock_resumes()
+	{
+		let next_run: DateTime<Cet> = HandlerTime::next_run_after(&at(3, 31, 1, 0), target(2, 30));
+
+		assert_eq!(next_run, at(3, 31, 3, 0));
+		assert_eq!(next_run.naive_utc(), NaiveDate::from_ymd_opt(2024, 3, 31).unwrap().and_hms_opt(1, 0, 0).unwrap());
+	}
+
+	#[test]
+	fn a_target_repeated_in_the_fall_runs_once()
+	{
+		let first: DateTime<Cet> = HandlerTime::next_run_after(&at(10, 27, 1, 0), target(2, 30));
+
+		assert_eq!(first.offset().local_minus_utc(), 7200);
+		assert_eq!(HandlerTime::next_run_after(&first, target(2, 30)), at(10, 28, 2, 30));
+	}
+
+	#[test]
+	fn an_invalid_time_of_day_is_rejected()
+	{
+		assert!(HandlerTime::new(24, 0).is_err());
+		assert!(HandlerTime::new(4, 60).is_err());
+		assert!(HandlerTime::new(23, 59).is_ok());
 	}
 }
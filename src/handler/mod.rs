@@ -1,18 +1,14 @@
 pub mod file;
 pub mod api;
 pub mod data;
+pub mod handler_archive_diff;
 pub mod handler_database_security_synchronizer;
 pub mod handler_time;
 
 pub use api::handler_api_sec::{ HandlerApiSec, UpdatedSecCompanyfactsAndSubmissions };
+pub use handler_archive_diff::HandlerArchiveDiff;
 pub use data::handler_security::{ HandlerSecurity, SynchronizeSecurity };
 pub use data::handler_security_exchange_ticker::HandlerSecurityExchangeTicker;
-pub use data::handler_filing_common_stock_shares_outstanding::{
-	HandlerFilingCommonStockSharesOutstanding
-};
-pub use data::handler_filing_entity_common_stock_shares_outstanding::{
-	HandlerFilingEntityCommonStockSharesOutstanding
-};
 pub use data::handler_security_filing::HandlerSecurityFiling;
 pub use handler_database_security_synchronizer::HandlerDatabaseSecuritySynchronizer;
 pub use handler_time::HandlerTime;
@@ -44,7 +44,7 @@ impl LogLevel
 		}
 	}
 
-	fn from_str(s: &str) -> Option<Self> {
+	pub fn from_str(s: &str) -> Option<Self> {
 		match s.to_ascii_lowercase().as_str() {
 			"error" => Some(LogLevel::Error),
 			"warn" => Some(LogLevel::Warn),
@@ -65,13 +65,22 @@ static LOG_LEVEL: Lazy<RwLock<LogLevel>> = Lazy::new(
 
 		let level = std::env::var("LOG_LEVEL").ok().as_deref().and_then(LogLevel::from_str).unwrap_or(LogLevel::Info);
 
-		println!("LOG_LEVEL: {:?}", level);
+		eprintln!("LOG_LEVEL: {:?}", level);
 
 		RwLock::new(level)
 	}
 );
 
 
+/**
+* Override the level read from LOG_LEVEL, e.g. with the one from the configuration file
+*/
+pub fn set_level(level: LogLevel)
+{
+	*LOG_LEVEL.write().unwrap() = level;
+}
+
+
 fn log(prefix: &str, level: LogLevel, args: Arguments)
 {
 	let current = *LOG_LEVEL.read().unwrap();
@@ -81,9 +90,10 @@ fn log(prefix: &str, level: LogLevel, args: Arguments)
 		let color = level.color_code();
 		let reset = "\x1b[0m";
 
+		// Logs go to stderr so stdout stays clean for command output (e.g. `diff --format json`).
 		// This colors the prefix only.
 		// Move {reset} to the end if you want the whole line colored.
-		println!("{}{} {} {}", color, prefix, reset, args);
+		eprintln!("{}{} {} {}", color, prefix, reset, args);
 	}
 }
 
@@ -1,15 +1,28 @@
+// Doc comments are indented with tabs, like the code around them
+#![allow(clippy::tabs_in_doc_comments)]
+
 mod logger;
+#[cfg(feature = "bench")]
+mod bench;
+mod config;
 mod database;
 mod handler;
 mod schema;
 
-use clap::Parser;
+use clap::{ Parser, Subcommand, ValueEnum };
 use dotenvy::dotenv;
+use std::path::PathBuf;
+use std::sync::Arc;
 use tokio::time::sleep;
 
-use chrono::{ Local };
+use chrono::{ Local, NaiveDate };
 
+use crate::config::Config;
+use crate::database::database_connection::DatabaseConnection;
+use crate::database::database_migration::{ DatabaseMigration, MigrationStatus };
+use crate::handler::HandlerArchiveDiff;
 use crate::handler::HandlerDatabaseSecuritySynchronizer;
+use crate::handler::HandlerSecurityExchangeTicker;
 use crate::handler::HandlerTime;
 use crate::handler::handler_time::Seconds;
 
@@ -18,9 +31,116 @@ use crate::handler::handler_time::Seconds;
 #[command(author, version, about, long_about = None)]
 struct Args
 {
-	/// Run the task immediately instead of waiting for 4am
+	/// Path to the TOML config file (defaults to APP__CONFIG, then ./config.toml when present)
+	#[arg(long, global = true)]
+	config: Option<PathBuf>,
+
+	/// Run the task immediately instead of waiting for the scheduled time
 	#[arg(long)]
 	run_now: bool,
+
+	/// Synchronize only these CIKs through the data.sec.gov APIs and exit (repeatable or comma separated)
+	#[arg(long = "cik", value_delimiter = ',')]
+	ciks: Vec<String>,
+
+	/// Synchronize offline from this submissions.zip and exit. No outbound HTTP is made.
+	#[arg(long, requires = "companyfacts_zip", conflicts_with = "ciks")]
+	submissions_zip: Option<PathBuf>,
+
+	/// Synchronize offline from this companyfacts.zip and exit. No outbound HTTP is made.
+	#[arg(long, requires = "submissions_zip", conflicts_with = "ciks")]
+	companyfacts_zip: Option<PathBuf>,
+
+	#[command(subcommand)]
+	command: Option<Command>,
+}
+
+
+#[derive(Subcommand)]
+enum Command
+{
+	/// Inspect the configuration
+	Config
+	{
+		#[command(subcommand)]
+		action: CommandConfig,
+	},
+
+	/// Apply, revert or list the embedded database schema migrations
+	Migrate
+	{
+		#[command(subcommand)]
+		action: CommandMigrate,
+	},
+
+	/// Report which CIKs were added, removed or changed between two submissions.zip or two companyfacts.zip files
+	Diff
+	{
+		/// The older archive, e.g. old.companyfacts.zip
+		old: PathBuf,
+
+		/// The newer archive, e.g. companyfacts.zip
+		new: PathBuf,
+
+		#[arg(long, value_enum, default_value_t = DiffFormat::Text)]
+		format: DiffFormat,
+	},
+
+	/// Print which CIK(s) held a ticker on a given date
+	Ticker
+	{
+		ticker: String,
+
+		/// Day to look up as YYYY-MM-DD (defaults to today)
+		#[arg(long)]
+		date: Option<NaiveDate>,
+	},
+
+	/// Compare the Value-tree and the streaming companyfacts parse on the largest files of a companyfacts.zip
+	#[cfg(feature = "bench")]
+	Bench
+	{
+		companyfacts_zip: PathBuf,
+
+		/// How many of the largest files to parse
+		#[arg(long, default_value_t = 20)]
+		files: usize,
+	},
+}
+
+
+#[derive(Clone, Copy, ValueEnum)]
+enum DiffFormat
+{
+	Text,
+	Json,
+}
+
+
+#[derive(Subcommand)]
+enum CommandMigrate
+{
+	/// Apply every pending migration
+	Up,
+
+	/// Revert the latest applied migration
+	Down
+	{
+		/// Revert every migration above this version instead (0 reverts everything)
+		#[arg(long)]
+		to: Option<i64>,
+	},
+
+	/// List the migrations and whether each one was applied
+	Status,
+}
+
+
+#[derive(Subcommand)]
+enum CommandConfig
+{
+	/// Validate the configuration and print the effective values
+	Check,
 }
 
 
@@ -36,7 +156,190 @@ async fn main() -> Result<(), Box<dyn std::error::Error>>
 
 	let args: Args = Args::parse();
 
-	let handler_security_profile = HandlerDatabaseSecuritySynchronizer::new();
+	let config: Config = match Config::load(args.config.as_deref())
+	{
+		Ok(config) => config,
+		Err(e) =>
+		{
+			log_error!("[ERROR] {}", e);
+
+			return Err(e);
+		},
+	};
+
+	if let Some(level) = logger::LogLevel::from_str(&config.log.level)
+	{
+		logger::set_level(level);
+	}
+
+	if let Some(Command::Config { action: CommandConfig::Check }) = &args.command
+	{
+		println!("{}", config.to_display_string()?);
+
+		config.validate_sec()?;
+
+		log_info!("Configuration is valid");
+
+		return Ok(());
+	}
+
+	if let Some(Command::Diff { old, new, format }) = &args.command
+	{
+		let archive_diff = HandlerArchiveDiff::new(old.clone(), new.clone()).diff()?;
+
+		match format
+		{
+			DiffFormat::Text => println!("{}", HandlerArchiveDiff::format_text(&archive_diff)),
+			DiffFormat::Json => println!("{}", serde_json::to_string_pretty(&archive_diff)?),
+		}
+
+		return Ok(());
+	}
+
+	#[cfg(feature = "bench")]
+	if let Some(Command::Bench { companyfacts_zip, files }) = &args.command
+	{
+		return bench::run(companyfacts_zip.clone(), *files, &config.facts.concepts);
+	}
+
+	if let Some(Command::Migrate { action }) = &args.command
+	{
+		let db_connection = Arc::new(DatabaseConnection::new(&config.database).await?);
+
+		let database_migration = DatabaseMigration::new(db_connection.clone());
+
+		match action
+		{
+			CommandMigrate::Up =>
+			{
+				database_migration.up().await?;
+
+				log_info!("Database schema is at version {}", database_migration.expected_version());
+			},
+			CommandMigrate::Down { to } =>
+			{
+				let version: i64 = database_migration.down(*to).await?;
+
+				log_info!("Database schema is at version {}", version);
+			},
+			CommandMigrate::Status =>
+			{
+				let statuses: Vec<MigrationStatus> = database_migration.status().await?;
+
+				for status in &statuses
+				{
+					println!(
+						"{:04}  {:<40}  {}",
+						status.version,
+						status.description,
+						match (status.applied, status.checksum_matches)
+						{
+							(true, true) => "applied",
+							(true, false) => "applied (checksum mismatch)",
+							(false, _) => "pending",
+						}
+					);
+				}
+
+				println!("Expected version: {}", database_migration.expected_version());
+			},
+		}
+
+		db_connection.close().await?;
+
+		return Ok(());
+	}
+
+	// Every command below uses the database, so refuse to run against a schema this binary was not built for
+	{
+		let db_connection = Arc::new(DatabaseConnection::new(&config.database).await?);
+
+		let result = DatabaseMigration::new(db_connection.clone()).check_version().await;
+
+		db_connection.close().await?;
+
+		if let Err(e) = result
+		{
+			log_error!("[ERROR] {}", e);
+
+			return Err(e);
+		}
+	}
+
+	if let Some(Command::Ticker { ticker, date }) = &args.command
+	{
+		let date: NaiveDate = date.unwrap_or_else(|| Local::now().date_naive());
+
+		let db_connection = Arc::new(DatabaseConnection::new(&config.database).await?);
+
+		let rows = HandlerSecurityExchangeTicker::new(db_connection.clone()).find_by_ticker_on_date(ticker, &date).await?;
+
+		for row in &rows
+		{
+			println!(
+				"{}\t{}\t{}\t{}\t{}",
+				row.security_cik,
+				row.exchange.as_deref().unwrap_or("NULL"),
+				row.ticker,
+				row.valid_from.map(|d| d.to_string()).unwrap_or_default(),
+				row.valid_to.map(|d| d.to_string()).unwrap_or_default()
+			);
+		}
+
+		if rows.is_empty()
+		{
+			log_warn!("No CIK held ticker {} on {}", ticker, date);
+		}
+
+		db_connection.close().await?;
+
+		return Ok(());
+	}
+
+	if let (Some(path_submissions_zip), Some(path_companyfacts_zip)) = (args.submissions_zip, args.companyfacts_zip)
+	{
+		log_info!("Running offline from supplied archives due to --submissions-zip and --companyfacts-zip flags");
+
+		let handler_security_profile = HandlerDatabaseSecuritySynchronizer::new(config);
+
+		if let Err(e) = handler_security_profile.synchronize_offline(path_submissions_zip, path_companyfacts_zip).await
+		{
+			log_error!("[ERROR] Error during offline execution: {}", e);
+
+			return Err(e);
+		}
+
+		log_info!("Offline execution completed. Exiting now <3");
+
+		return Ok(());
+	}
+
+	if let Err(e) = config.validate_sec()
+	{
+		log_error!("[ERROR] {}", e);
+
+		return Err(e);
+	}
+
+	let time_handler = HandlerTime::new(config.schedule.hour, config.schedule.minute)?;
+
+	let handler_security_profile = HandlerDatabaseSecuritySynchronizer::new(config);
+
+	if !args.ciks.is_empty()
+	{
+		log_info!("Synchronizing {} CIK(s) through the SEC API due to --cik flag", args.ciks.len());
+
+		if let Err(e) = handler_security_profile.synchronize_ciks(&args.ciks).await
+		{
+			log_error!("[ERROR] Error during CIK synchronization: {}", e);
+
+			return Err(e);
+		}
+
+		log_info!("CIK synchronization completed. Exiting now <3");
+
+		return Ok(());
+	}
 
 	if args.run_now
 	{
@@ -54,14 +357,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>>
 		return Ok(());
 	}
 
-	let time_handler = HandlerTime::new();
-
 	loop
 	{
-		let initial_delay: Seconds = time_handler.calculate_seconds_until_next_4am();
+		let initial_delay: Seconds = time_handler.calculate_seconds_until_next_run();
 
 		log_info!(
-			"[INFO] Calculated time until next 4am execution: {}h {}m {}s",
+			"[INFO] Calculated time until next scheduled execution: {}h {}m {}s",
 			initial_delay.as_secs() / 3600,
 			(initial_delay.as_secs() % 3600) / 60,
 			initial_delay.as_secs() % 60
@@ -0,0 +1,62 @@
+use serde::Serialize;
+
+use super::SecArchive;
+
+
+/**
+* A metadata field whose value differs between the old and the new archive
+*/
+#[derive(Debug, Serialize)]
+pub struct ArchiveDiffField
+{
+	pub field: String,
+	pub old: String,
+	pub new: String,
+}
+
+/**
+* A fact value present in the new companyfacts archive but not in the old one
+*/
+#[derive(Debug, Serialize)]
+pub struct ArchiveDiffFact
+{
+	pub taxonomy: String,
+	pub concept: String,
+	pub unit: String,
+	pub accession_number: String,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub start: Option<String>,
+	pub end: String,
+	pub val: String,
+}
+
+/**
+* Semantic summary of a CIK whose files differ between the two archives
+*/
+#[derive(Debug, Default, Serialize)]
+pub struct ArchiveDiffChanged
+{
+	pub cik: String,
+	#[serde(skip_serializing_if = "Vec::is_empty")]
+	pub new_accession_numbers: Vec<String>,
+	#[serde(skip_serializing_if = "Vec::is_empty")]
+	pub tickers_added: Vec<String>,
+	#[serde(skip_serializing_if = "Vec::is_empty")]
+	pub tickers_removed: Vec<String>,
+	#[serde(skip_serializing_if = "Vec::is_empty")]
+	pub fields_changed: Vec<ArchiveDiffField>,
+	#[serde(skip_serializing_if = "Vec::is_empty")]
+	pub new_facts: Vec<ArchiveDiffFact>,
+}
+
+/**
+* Difference between two submissions.zip or two companyfacts.zip files, keyed by CIK
+*/
+#[derive(Debug, Serialize)]
+pub struct ArchiveDiff
+{
+	pub archive: SecArchive,
+	pub added: Vec<String>,
+	pub removed: Vec<String>,
+	pub changed: Vec<ArchiveDiffChanged>,
+}
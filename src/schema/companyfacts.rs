@@ -1,37 +1,104 @@
-#[derive(Debug)]
-pub struct Assets
+use rust_decimal::Decimal;
+use serde::{ Deserialize, Serialize };
+
+
+/**
+* An XBRL concept to ingest into filing_fact, e.g. us-gaap:Revenues. Facts reported under one of its `equivalents`
+* (e.g. ifrs-full:Revenue for IFRS filers) are ingested too, with this concept as their canonical name.
+*/
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct FactConcept
 {
-	pub security_filing_accession_number: String,
-	pub end: String,
-	pub fp: String,
-	pub fy: i64,
-	pub val: i64,
+	pub taxonomy: String,
+	pub concept: String,
+	/// Only this unit (e.g. USD). Every unit when unset, so EUR, JPY or CAD reporters are included.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub unit: Option<String>,
+	/// Other `taxonomy:concept` names for the same concept
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub equivalents: Vec<String>,
 }
 
-#[derive(Debug)]
-pub struct CommonStockSharesOutstanding
+impl FactConcept
 {
-	pub security_filing_accession_number: String,
-	pub end: String,
-	pub fp: String,
-	pub fy: i64,
-	pub val: i64,
+	pub fn new(taxonomy: &str, concept: &str, unit: Option<&str>) -> Self
+	{
+		Self
+		{
+			taxonomy: taxonomy.to_string(),
+			concept: concept.to_string(),
+			unit: unit.map(String::from),
+			equivalents: Vec::new(),
+		}
+	}
+
+	pub fn with_equivalents(mut self, equivalents: &[&str]) -> Self
+	{
+		self.equivalents = equivalents.iter().map(|e| e.to_string()).collect();
+
+		self
+	}
+
+	/**
+	* `taxonomy:concept`, the name facts of this concept and its equivalents are stored under
+	*/
+	pub fn canonical(&self) -> String
+	{
+		format!("{}:{}", self.taxonomy, self.concept)
+	}
+
+	/**
+	* Every `taxonomy:concept` name this concept is reported under
+	*/
+	pub fn names(&self) -> Vec<String>
+	{
+		std::iter::once(self.canonical()).chain(self.equivalents.iter().cloned()).collect()
+	}
+
+	/**
+	* Whether the facts of `other` are all extracted by this concept
+	*/
+	pub fn covers(&self, other: &FactConcept) -> bool
+	{
+		self.names().contains(&other.canonical()) && (self.unit.is_none() || self.unit == other.unit)
+	}
 }
 
-#[derive(Debug)]
-pub struct EntityCommonStockSharesOutstanding
+#[derive(Debug, Clone)]
+pub struct Fact
 {
+	/// `taxonomy:concept` of the configured concept this fact was extracted for
+	pub canonical_concept: String,
+	pub taxonomy: String,
+	pub concept: String,
 	pub security_filing_accession_number: String,
+	pub unit: String,
+	/// Only set for duration facts (e.g. revenue for a quarter)
+	pub start: Option<String>,
 	pub end: String,
 	pub fp: String,
 	pub fy: i64,
-	pub val: i64,
+	pub val: Decimal,
+	/// Required for filing_fact only, the legacy tables keep facts SEC sent without it
+	pub filed: Option<String>,
+	/// e.g. 10-K or 10-K/A, which tells an original value from a restatement. Required for filing_fact only.
+	pub form: Option<String>,
+	/// Calendar frame (e.g. CY2023Q4I) SEC assigned the fact to, if any
+	pub frame: Option<String>,
 }
 
 #[derive(Debug)]
 pub struct Companyfacts
 {
-	pub assets: Vec<Assets>,
-	pub common_stock_shares_outstanding: Vec<CommonStockSharesOutstanding>,
-	pub entity_common_stock_shares_outstanding: Vec<EntityCommonStockSharesOutstanding>,
+	pub cik: String,
+	pub facts: Vec<Fact>,
+	/// us-gaap:Assets in USD for filing_assets
+	pub assets: Vec<Fact>,
+	/// us-gaap:CommonStockSharesOutstanding in shares for filing_common_stock_shares_outstanding
+	pub common_stock_shares_outstanding: Vec<Fact>,
+	/// dei:EntityCommonStockSharesOutstanding in shares for filing_entity_common_stock_shares_outstanding
+	pub entity_common_stock_shares_outstanding: Vec<Fact>,
+	/// Facts of the extracted concepts skipped for a missing field or a value that is not a number
+	pub dropped_facts: usize,
 }
@@ -0,0 +1,37 @@
+use serde::Serialize;
+
+
+/**
+* Change-detection fingerprint of a file inside a SEC zip, taken from the zip central directory. The SHA-256 is only
+* computed in strict mode since it requires decompressing the file.
+*/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileFingerprint
+{
+	pub crc32: u32,
+	pub size: u64,
+	pub sha256: Option<String>,
+}
+
+/**
+* SEC bulk archive a fingerprinted file belongs to
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SecArchive
+{
+	Companyfacts,
+	Submissions,
+}
+
+impl SecArchive
+{
+	pub fn as_str(&self) -> &'static str
+	{
+		match self
+		{
+			SecArchive::Companyfacts => "companyfacts",
+			SecArchive::Submissions => "submissions",
+		}
+	}
+}
@@ -0,0 +1,268 @@
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use serde::de::{ DeserializeSeed, Deserializer, IgnoredAny, MapAccess, Visitor };
+use serde_json::Value;
+use std::collections::{ BTreeMap, HashSet };
+use std::fmt;
+
+use super::json_submissions::deserialize_string_or_number;
+
+
+/**
+* One entry of `facts.<taxonomy>.<concept>.units.<unit>`. A field that is missing or of the wrong type (`"fy": "2020"`)
+* reads as None, and an entry that is not an object reads as all None, so a malformed entry is counted as dropped
+* instead of failing the whole file.
+*/
+#[derive(Debug, Default)]
+pub struct JsonCompanyfactsItem
+{
+	pub accn: Option<String>,
+	pub start: Option<String>,
+	pub end: Option<String>,
+	pub fp: Option<String>,
+	pub fy: Option<i64>,
+	pub val: Option<Value>,
+	pub filed: Option<String>,
+	pub form: Option<String>,
+	pub frame: Option<String>,
+}
+
+impl<'de> Deserialize<'de> for JsonCompanyfactsItem
+{
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		let Value::Object(mut fields) = Value::deserialize(deserializer)? else { return Ok(Self::default()) };
+
+		let mut field = |name: &str| fields.remove(name).filter(|value| !value.is_null());
+
+		Ok(
+			Self
+			{
+				accn: lenient(field("accn")),
+				start: lenient(field("start")),
+				end: lenient(field("end")),
+				fp: lenient(field("fp")),
+				fy: lenient(field("fy")),
+				val: field("val"),
+				filed: lenient(field("filed")),
+				form: lenient(field("form")),
+				frame: lenient(field("frame")),
+			}
+		)
+	}
+}
+
+/**
+* The value as a T, or None if it is not one
+*/
+fn lenient<T: DeserializeOwned>(value: Option<Value>) -> Option<T>
+{
+	T::deserialize(value?).ok()
+}
+
+/**
+* The units of one concept we asked for
+*/
+#[derive(Debug)]
+pub struct JsonCompanyfactsConcept
+{
+	pub taxonomy: String,
+	pub concept: String,
+	pub units: BTreeMap<String, Vec<JsonCompanyfactsItem>>,
+}
+
+/**
+* A companyfacts JSON (`CIK##########.json`) reduced to the concepts we asked for
+*/
+#[derive(Debug, Default)]
+pub struct JsonCompanyfacts
+{
+	pub cik: Option<String>,
+	pub entity_name: Option<String>,
+	pub concepts: Vec<JsonCompanyfactsConcept>,
+}
+
+
+/**
+* Streams a companyfacts JSON and keeps only the concepts named in `names` (`taxonomy:concept`). Everything else,
+* which is most of a large file, is skipped with IgnoredAny without being allocated.
+*/
+pub struct JsonCompanyfactsSeed<'a>
+{
+	pub names: &'a HashSet<String>,
+}
+
+impl<'de, 'a> DeserializeSeed<'de> for JsonCompanyfactsSeed<'a>
+{
+	type Value = JsonCompanyfacts;
+
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		deserializer.deserialize_map(self)
+	}
+}
+
+impl<'de, 'a> Visitor<'de> for JsonCompanyfactsSeed<'a>
+{
+	type Value = JsonCompanyfacts;
+
+	fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result
+	{
+		formatter.write_str("a companyfacts object")
+	}
+
+	fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+	where
+		A: MapAccess<'de>,
+	{
+		let mut json_companyfacts: JsonCompanyfacts = JsonCompanyfacts::default();
+
+		while let Some(key) = map.next_key::<String>()?
+		{
+			match key.as_str()
+			{
+				"cik" => json_companyfacts.cik = map.next_value_seed(StringOrNumberSeed)?,
+				"entityName" => json_companyfacts.entity_name = map.next_value()?,
+				"facts" => map.next_value_seed(TaxonomiesSeed { names: self.names, out: &mut json_companyfacts.concepts })?,
+				_ => { map.next_value::<IgnoredAny>()?; },
+			}
+		}
+
+		Ok(json_companyfacts)
+	}
+}
+
+
+struct StringOrNumberSeed;
+
+impl<'de> DeserializeSeed<'de> for StringOrNumberSeed
+{
+	type Value = Option<String>;
+
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		deserialize_string_or_number(deserializer)
+	}
+}
+
+
+/**
+* `facts`: taxonomy -> concepts
+*/
+struct TaxonomiesSeed<'a, 'b>
+{
+	names: &'a HashSet<String>,
+	out: &'b mut Vec<JsonCompanyfactsConcept>,
+}
+
+impl<'de, 'a, 'b> DeserializeSeed<'de> for TaxonomiesSeed<'a, 'b>
+{
+	type Value = ();
+
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		deserializer.deserialize_map(self)
+	}
+}
+
+impl<'de, 'a, 'b> Visitor<'de> for TaxonomiesSeed<'a, 'b>
+{
+	type Value = ();
+
+	fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result
+	{
+		formatter.write_str("a map of taxonomies")
+	}
+
+	fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+	where
+		A: MapAccess<'de>,
+	{
+		while let Some(taxonomy) = map.next_key::<String>()?
+		{
+			map.next_value_seed(ConceptsSeed { names: self.names, taxonomy: &taxonomy, out: self.out })?;
+		}
+
+		Ok(())
+	}
+}
+
+
+/**
+* `facts.<taxonomy>`: concept -> { label, description, units }
+*/
+struct ConceptsSeed<'a, 'b, 'c>
+{
+	names: &'a HashSet<String>,
+	taxonomy: &'c str,
+	out: &'b mut Vec<JsonCompanyfactsConcept>,
+}
+
+impl<'de, 'a, 'b, 'c> DeserializeSeed<'de> for ConceptsSeed<'a, 'b, 'c>
+{
+	type Value = ();
+
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		deserializer.deserialize_map(self)
+	}
+}
+
+impl<'de, 'a, 'b, 'c> Visitor<'de> for ConceptsSeed<'a, 'b, 'c>
+{
+	type Value = ();
+
+	fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result
+	{
+		formatter.write_str("a map of concepts")
+	}
+
+	fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+	where
+		A: MapAccess<'de>,
+	{
+		while let Some(concept) = map.next_key::<String>()?
+		{
+			if !self.names.contains(&format!("{}:{}", self.taxonomy, concept))
+			{
+				map.next_value::<IgnoredAny>()?;
+
+				continue;
+			}
+
+			let json_concept: JsonConceptUnits = map.next_value()?;
+
+			self.out.push(
+				JsonCompanyfactsConcept
+				{
+					taxonomy: self.taxonomy.to_string(),
+					concept,
+					units: json_concept.units,
+				}
+			);
+		}
+
+		Ok(())
+	}
+}
+
+
+/**
+* `facts.<taxonomy>.<concept>`, of which only `units` is kept
+*/
+#[derive(Deserialize)]
+struct JsonConceptUnits
+{
+	#[serde(default)]
+	units: BTreeMap<String, Vec<JsonCompanyfactsItem>>,
+}
@@ -0,0 +1,140 @@
+use serde::{ Deserialize, Deserializer };
+use serde_json::Value;
+
+
+/**
+* Accept a JSON string or number, e.g. `cik` is a string in submissions but a number in companyfacts
+*/
+pub fn deserialize_string_or_number<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+	D: Deserializer<'de>,
+{
+	Ok(
+		match Option::<Value>::deserialize(deserializer)?
+		{
+			Some(Value::String(s)) => Some(s),
+			Some(Value::Number(n)) => Some(n.to_string()),
+			_ => None,
+		}
+	)
+}
+
+/**
+* Accept a JSON boolean or a 0/1 number, e.g. `insiderTransactionForOwnerExists`
+*/
+pub fn deserialize_bool_or_number<'de, D>(deserializer: D) -> Result<Option<bool>, D::Error>
+where
+	D: Deserializer<'de>,
+{
+	Ok(
+		match Option::<Value>::deserialize(deserializer)?
+		{
+			Some(Value::Bool(b)) => Some(b),
+			Some(Value::Number(n)) => Some(n.as_i64() != Some(0)),
+			_ => None,
+		}
+	)
+}
+
+
+/**
+* Column arrays of `filings.recent`, and the whole content of an older filings file (`CIK##########-submissions-###.json`)
+*/
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct JsonSubmissionsFilingsArrays
+{
+	pub accession_number: Vec<Option<String>>,
+	pub filing_date: Vec<Option<String>>,
+	pub report_date: Vec<Option<String>>,
+	pub acceptance_date_time: Vec<Option<String>>,
+	pub form: Vec<Option<String>>,
+	pub act: Vec<Option<String>>,
+	pub file_number: Vec<Option<String>>,
+	pub film_number: Vec<Option<String>>,
+	pub items: Vec<Option<String>>,
+	pub size: Vec<Option<i64>>,
+	#[serde(rename = "isXBRL")]
+	pub is_xbrl: Vec<Option<i64>>,
+	#[serde(rename = "isInlineXBRL")]
+	pub is_inline_xbrl: Vec<Option<i64>>,
+	pub primary_document: Vec<Option<String>>,
+	pub primary_doc_description: Vec<Option<String>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct JsonSubmissionsFilingsFile
+{
+	pub name: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct JsonSubmissionsFilings
+{
+	pub recent: JsonSubmissionsFilingsArrays,
+	pub files: Vec<JsonSubmissionsFilingsFile>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct JsonSubmissionsAddress
+{
+	pub street1: Option<String>,
+	pub city: Option<String>,
+	pub state_or_country: Option<String>,
+	pub country: Option<String>,
+	pub zip_code: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct JsonSubmissionsAddresses
+{
+	pub business: JsonSubmissionsAddress,
+	pub mailing: JsonSubmissionsAddress,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct JsonSubmissionsFormerName
+{
+	pub name: Option<String>,
+	pub from: Option<String>,
+	pub to: Option<String>,
+}
+
+/**
+* A submissions JSON (`CIK##########.json`). Only the fields we use are modelled, everything else is skipped while
+* streaming instead of being built into a `serde_json::Value` tree.
+*/
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct JsonSubmissions
+{
+	#[serde(deserialize_with = "deserialize_string_or_number")]
+	pub cik: Option<String>,
+	pub name: Option<String>,
+	pub tickers: Vec<Option<String>>,
+	pub exchanges: Vec<Option<String>>,
+	pub addresses: JsonSubmissionsAddresses,
+	pub description: Option<String>,
+	pub ein: Option<String>,
+	pub entity_type: Option<String>,
+	pub phone: Option<String>,
+	pub sic: Option<String>,
+	pub sic_description: Option<String>,
+	pub category: Option<String>,
+	pub fiscal_year_end: Option<String>,
+	pub state_of_incorporation: Option<String>,
+	pub owner_org: Option<String>,
+	#[serde(deserialize_with = "deserialize_bool_or_number")]
+	pub insider_transaction_for_owner_exists: Option<bool>,
+	#[serde(deserialize_with = "deserialize_bool_or_number")]
+	pub insider_transaction_for_issuer_exists: Option<bool>,
+	pub flags: Option<String>,
+	pub website: Option<String>,
+	pub investor_website: Option<String>,
+	pub former_names: Vec<JsonSubmissionsFormerName>,
+	pub filings: JsonSubmissionsFilings,
+}
@@ -1,11 +1,26 @@
+pub mod archive_diff;
 pub mod companyfacts;
+pub mod file_fingerprint;
+pub mod json_companyfacts;
+pub mod json_submissions;
+pub mod parse_error;
 pub mod submissions_data;
 
-pub use companyfacts::{
-	Assets,
-	Companyfacts,
-	CommonStockSharesOutstanding,
-	EntityCommonStockSharesOutstanding,
-};
+pub use archive_diff::{ ArchiveDiff, ArchiveDiffChanged, ArchiveDiffFact, ArchiveDiffField };
+
+pub use companyfacts::{ Companyfacts, Fact, FactConcept };
+
+pub use file_fingerprint::{ FileFingerprint, SecArchive };
+
+pub use json_companyfacts::{ JsonCompanyfacts, JsonCompanyfactsSeed };
 
-pub use submissions_data::{ SubmissionsData, SubmissionsDataFilings };
+pub use json_submissions::{ JsonSubmissions, JsonSubmissionsFilingsArrays, JsonSubmissionsFormerName };
+
+pub use parse_error::ParseError;
+
+pub use submissions_data::{
+	SubmissionsData,
+	SubmissionsDataExchangeTicker,
+	SubmissionsDataFilings,
+	SubmissionsDataFormerName,
+};
@@ -0,0 +1,80 @@
+use std::fmt;
+
+
+/**
+* A file of a SEC archive that could not be parsed, with enough detail to find the offending value.
+* `index` is the position in a filings column array, when the failure is in one.
+*/
+#[derive(Debug, Clone)]
+pub struct ParseError
+{
+	pub file_name: String,
+	pub field: String,
+	pub index: Option<usize>,
+	pub raw_value: Option<String>,
+	pub message: String,
+}
+
+impl ParseError
+{
+	pub fn new(field: &str, index: Option<usize>, raw_value: Option<&str>, message: &str) -> Self
+	{
+		Self
+		{
+			file_name: String::new(),
+			field: field.to_string(),
+			index,
+			raw_value: raw_value.map(String::from),
+			message: message.to_string(),
+		}
+	}
+
+	/**
+	* Wrap an error that carries no field information, e.g. malformed JSON
+	*/
+	pub fn from_error(file_name: &str, e: &dyn std::error::Error) -> Self
+	{
+		Self
+		{
+			file_name: file_name.to_string(),
+			field: String::new(),
+			index: None,
+			raw_value: None,
+			message: e.to_string(),
+		}
+	}
+
+	pub fn with_file_name(mut self, file_name: &str) -> Self
+	{
+		self.file_name = file_name.to_string();
+
+		self
+	}
+}
+
+impl fmt::Display for ParseError
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+	{
+		write!(f, "{}", self.file_name)?;
+
+		if !self.field.is_empty()
+		{
+			write!(f, " {}", self.field)?;
+		}
+
+		if let Some(index) = self.index
+		{
+			write!(f, "[{}]", index)?;
+		}
+
+		if let Some(raw_value) = &self.raw_value
+		{
+			write!(f, " = {:?}", raw_value)?;
+		}
+
+		write!(f, ": {}", self.message)
+	}
+}
+
+impl std::error::Error for ParseError {}
@@ -1,4 +1,5 @@
 use chrono::{ NaiveDate, NaiveDateTime };
+use std::fmt;
 
 
 #[derive(Debug)]
@@ -9,6 +10,49 @@ pub struct SubmissionsDataFilings
 	pub form: String,
 	pub report_date: Option<NaiveDate>,
 	pub acceptance: NaiveDateTime,
+	pub act: String,
+	pub file_number: String,
+	pub film_number: String,
+	pub items: String,
+	pub size: Option<i64>,
+	pub is_xbrl: bool,
+	pub is_inline_xbrl: bool,
+	pub primary_document: String,
+	pub primary_doc_description: String,
+}
+
+/**
+* One listing of a submission. `exchange` is None when EDGAR does not know the exchange of the ticker.
+*/
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SubmissionsDataExchangeTicker
+{
+	pub exchange: Option<String>,
+	pub ticker: String,
+}
+
+impl fmt::Display for SubmissionsDataExchangeTicker
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+	{
+		match &self.exchange
+		{
+			Some(exchange) => write!(f, "{} ({})", self.ticker, exchange),
+			None => write!(f, "{}", self.ticker),
+		}
+	}
+}
+
+/**
+* A name the entity was known by, with the period it was used. `from`/`to` are dates only, EDGAR sends them as
+* midnight UTC timestamps.
+*/
+#[derive(Debug)]
+pub struct SubmissionsDataFormerName
+{
+	pub name: String,
+	pub from: Option<NaiveDate>,
+	pub to: Option<NaiveDate>,
 }
 
 #[derive(Debug)]
@@ -16,21 +60,36 @@ pub struct SubmissionsData
 {
 	pub cik: String,
 
-	pub tickers: Vec<String>,
-	pub exchanges: Vec<String>,
+	pub exchange_tickers: Vec<SubmissionsDataExchangeTicker>,
 
 	pub business_country: String,
 	pub business_city: String,
 	pub business_state: String,
 	pub business_street1: String,
 	pub business_zip: String,
+	pub mailing_country: String,
+	pub mailing_city: String,
+	pub mailing_state: String,
+	pub mailing_street1: String,
+	pub mailing_zip: String,
+	pub category: String,
 	pub description: String,
 	pub ein: String,
 	pub entity_type: String,
+	pub fiscal_year_end: String,
+	pub flags: String,
+	pub insider_transaction_for_issuer_exists: bool,
+	pub insider_transaction_for_owner_exists: bool,
+	pub investor_website: String,
 	pub name: String,
+	pub owner_org: String,
 	pub phone: String,
 	pub sic: String,
+	pub sic_description: String,
+	pub state_of_incorporation: String,
 	pub website: String,
 
+	pub former_names: Vec<SubmissionsDataFormerName>,
+
 	pub filings: Vec<SubmissionsDataFilings>,
 }